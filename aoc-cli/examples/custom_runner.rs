@@ -0,0 +1,47 @@
+//! Example of the full `aoc` CLI over solvers defined outside aoc-solutions
+//!
+//! Solvers derived with `AutoRegisterSolver` in any linked crate are picked up
+//! as plugins; solvers registered by hand go in the builder passed to `main!`.
+//!
+//! Run with: cargo run -p aoc-cli --example custom_runner -- list
+
+use aoc_solver::{
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError,
+    SolverRegistryBuilder,
+};
+
+/// A solver registered through the plugin system
+#[derive(AocSolver, AutoRegisterSolver)]
+#[aoc_solver(max_parts = 1)]
+#[aoc(year = 2015, day = 1, tags = ["example"])]
+struct NotQuiteLisp;
+
+impl AocParser for NotQuiteLisp {
+    type SharedData<'a> = &'a str;
+
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        Ok(input.trim())
+    }
+}
+
+impl PartSolver<1> for NotQuiteLisp {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        let floor: i64 = shared.chars().map(|c| if c == '(' { 1 } else { -1 }).sum();
+        Ok(floor.to_string())
+    }
+}
+
+/// Same puzzle, part 1 only, registered by hand for another year
+fn builder() -> SolverRegistryBuilder {
+    let mut builder = SolverRegistryBuilder::new();
+    builder
+        .register(2016, 1, 1, |input: &str| {
+            Ok(Box::new(aoc_solver::SolverInstance::<NotQuiteLisp>::new(
+                2016, 1, input,
+            )?))
+        })
+        .expect("2016/1 is registered once");
+    builder
+}
+
+aoc_cli::main!(builder());
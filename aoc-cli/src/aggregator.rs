@@ -111,7 +111,12 @@ mod tests {
             part,
             answer: Ok(format!("{}_{}_{}", year, day, part)),
             solve_duration: TimeDelta::milliseconds(10),
+            solve_cpu_duration: None,
             parse_duration: Some(TimeDelta::milliseconds(5)),
+            parse_cpu_duration: None,
+            parse_arena: None,
+            validation_error: None,
+            verification: None,
             submitted_at: None,
             submission: None,
             submission_wait: None,
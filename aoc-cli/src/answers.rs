@@ -0,0 +1,144 @@
+//! Local store of answers confirmed by Advent of Code
+//!
+//! Stored per user as `answers.tsv` in the cache directory, one
+//! `{year}_day{day:02}<TAB>{part}<TAB>{answer}` line per solved part.
+
+use std::collections::BTreeMap;
+
+/// Outcome of checking a computed answer against the store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    /// The answer matches the confirmed answer
+    Verified,
+    /// The answer differs from the confirmed answer
+    Regressed { expected: String },
+}
+
+/// Confirmed answers keyed by (year, day, part)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u16, u8, u8), String>,
+}
+
+impl AnswerStore {
+    /// Parse the TSV format, skipping blank, comment and malformed lines
+    pub fn parse(content: &str) -> Self {
+        let answers = content
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                let (key, part, answer) = (fields.next()?, fields.next()?, fields.next()?);
+                let (year, day) = key.split_once("_day")?;
+                let key = (year.parse().ok()?, day.parse().ok()?, part.parse().ok()?);
+                Some((key, answer.trim().to_string()))
+            })
+            .collect();
+        Self { answers }
+    }
+
+    /// Serialize to the TSV format, ordered by year, day and part
+    pub fn to_tsv(&self) -> String {
+        self.answers
+            .iter()
+            .map(|((year, day, part), answer)| {
+                format!("{}_day{:02}\t{}\t{}\n", year, day, part, answer)
+            })
+            .collect()
+    }
+
+    /// Get the confirmed answer for a part
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Record a confirmed answer, returning true if the store changed
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, answer: impl Into<String>) -> bool {
+        let answer = answer.into();
+        let previous = self.answers.insert((year, day, part), answer.clone());
+        previous.as_ref() != Some(&answer)
+    }
+
+    /// Forget a confirmed answer, returning it if it was stored
+    pub fn remove(&mut self, year: u16, day: u8, part: u8) -> Option<String> {
+        self.answers.remove(&(year, day, part))
+    }
+
+    /// Iterate over `((year, day, part), answer)`, ordered by year, day and part
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8, u8), &str)> {
+        self.answers
+            .iter()
+            .map(|(key, answer)| (*key, answer.as_str()))
+    }
+
+    /// Compare a computed answer with the confirmed one, if known
+    pub fn verify(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Verification> {
+        self.get(year, day, part).map(|expected| {
+            if expected == answer {
+                Verification::Verified
+            } else {
+                Verification::Regressed {
+                    expected: expected.to_string(),
+                }
+            }
+        })
+    }
+
+    /// Number of stored answers
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_verify() {
+        let mut store = AnswerStore::default();
+        assert!(store.insert(2023, 5, 1, "35"));
+        assert!(!store.insert(2023, 5, 1, "35"));
+
+        assert_eq!(store.verify(2023, 5, 1, "35"), Some(Verification::Verified));
+        assert_eq!(
+            store.verify(2023, 5, 1, "36"),
+            Some(Verification::Regressed {
+                expected: "35".to_string()
+            })
+        );
+        assert_eq!(store.verify(2023, 5, 2, "35"), None);
+
+        assert_eq!(store.remove(2023, 5, 1).as_deref(), Some("35"));
+        assert_eq!(store.remove(2023, 5, 1), None);
+        assert_eq!(store.verify(2023, 5, 1, "35"), None);
+    }
+
+    #[test]
+    fn test_parse_skips_malformed_lines() {
+        let store =
+            AnswerStore::parse("# comment\n2023_day05\t1\t35\n\nbogus line\n2023_day05\tx\t1\n");
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.get(2023, 5, 1), Some("35"));
+    }
+
+    // **Feature: answer-store, Property 1: TSV round trip**
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(50))]
+
+        #[test]
+        fn prop_tsv_roundtrip(
+            entries in prop::collection::vec(
+                (2015u16..2035, 1u8..=25, 1u8..=2, "[A-Za-z0-9,]{1,20}"),
+                0..20,
+            ),
+        ) {
+            let mut store = AnswerStore::default();
+            for (year, day, part, answer) in entries {
+                store.insert(year, day, part, answer);
+            }
+            prop_assert_eq!(AnswerStore::parse(&store.to_tsv()), store);
+        }
+    }
+}
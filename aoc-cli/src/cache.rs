@@ -1,12 +1,57 @@
 //! Input cache for storing puzzle inputs locally
 
+use crate::answers::AnswerStore;
 use crate::error::CacheError;
+use crate::history::SubmissionLog;
+use aoc_http_client::{PrivateLeaderboard, check_input};
+use memmap2::Mmap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-/// File-based cache for puzzle inputs
+/// Puzzle input bytes, either memory-mapped from the cache or owned
+pub enum InputData {
+    /// Read-only memory map of a cached input file
+    Mapped(Mmap),
+    /// Input held in memory (e.g. freshly fetched)
+    Owned(String),
+}
+
+impl Deref for InputData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            InputData::Mapped(map) => map,
+            InputData::Owned(s) => s.as_bytes(),
+        }
+    }
+}
+
+/// An example input saved from a puzzle description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleCase {
+    /// Part the expected answer belongs to
+    pub part: u8,
+    /// 1-based index of the example within the part
+    pub index: usize,
+    /// Example input
+    pub input: String,
+    /// Expected answer, if one was found
+    pub answer: Option<String>,
+}
+
+/// File-based cache for puzzle inputs and descriptions
 ///
-/// Directory structure: `{user_dir}/{year}_day{day:02}.txt`
+/// Directory structure: `{user_dir}/{year}_day{day:02}.txt` for inputs,
+/// `{user_dir}/{year}_day{day:02}.md` for puzzle descriptions and
+/// `{user_dir}/{year}_day{day:02}_p{part}_ex{n}.txt` (plus `.answer`) for examples.
+/// Confirmed answers for all days live in `{user_dir}/answers.tsv`, submitted answers
+/// in `{user_dir}/submissions.tsv`, and private
+/// leaderboards in `{user_dir}/leaderboard_{year}_{id}.json`.
+#[derive(Clone)]
 pub struct InputCache {
     /// Pre-computed user directory: `{base_dir}/{user_id}`
     user_dir: PathBuf,
@@ -36,6 +81,7 @@ impl InputCache {
     }
 
     /// Get cached input or None if not cached
+    #[allow(dead_code)] // Solvers read through `get_mapped`; kept for owned UTF-8 access
     pub fn get(&self, year: u16, day: u8) -> Result<Option<String>, CacheError> {
         let path = self.cache_path(year, day);
         if path.exists() {
@@ -46,10 +92,212 @@ impl InputCache {
         }
     }
 
+    /// Get a read-only memory map of the cached input, or None if not cached
+    ///
+    /// Solvers can borrow from the mapped bytes without copying or UTF-8 validation.
+    /// Mappings stay valid while the cache is updated because [`put`](Self::put)
+    /// replaces files atomically instead of writing into them.
+    pub fn get_mapped(&self, year: u16, day: u8) -> Result<Option<Mmap>, CacheError> {
+        let path = self.cache_path(year, day);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let file = fs::File::open(&path)?;
+        // SAFETY: cache files are never modified in place: `put` writes a new file and
+        // renames it over the old one, so the mapped inode keeps its contents for the
+        // life of the map. External edits to the cache directory are not supported.
+        let map = unsafe { Mmap::map(&file)? };
+        Ok(Some(map))
+    }
+
     /// Store input in cache
+    ///
+    /// Writes to a temporary file and renames it into place, so existing memory
+    /// maps of the previous contents remain valid. AoC error pages (see
+    /// [`check_input`]) are refused with `CacheError::InvalidInput`.
     pub fn put(&self, year: u16, day: u8, input: &str) -> Result<(), CacheError> {
-        let path = self.cache_path(year, day);
+        check_input(input).map_err(CacheError::InvalidInput)?;
+        self.write_atomic(&self.cache_path(year, day), input)
+    }
+
+    /// Get the cache path for a puzzle description
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.user_dir.join(format!("{}_day{:02}.md", year, day))
+    }
+
+    /// Get a cached puzzle description (Markdown) or None if not cached
+    pub fn get_puzzle(&self, year: u16, day: u8) -> Result<Option<String>, CacheError> {
+        let path = self.puzzle_path(year, day);
+        if path.exists() {
+            Ok(Some(fs::read_to_string(&path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Store a puzzle description (Markdown) in the cache
+    pub fn put_puzzle(&self, year: u16, day: u8, markdown: &str) -> Result<(), CacheError> {
+        self.write_atomic(&self.puzzle_path(year, day), markdown)
+    }
+
+    /// Get the cached examples for a year/day, ordered by part and index
+    ///
+    /// Returns an empty list if no examples are cached.
+    pub fn get_examples(&self, year: u16, day: u8) -> Result<Vec<ExampleCase>, CacheError> {
+        if !self.user_dir.exists() {
+            return Ok(Vec::new());
+        }
+        let prefix = example_prefix(year, day);
+        let mut examples = Vec::new();
+        for entry in fs::read_dir(&self.user_dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some((part, index)) = name
+                .strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix(".txt"))
+                .and_then(|rest| rest.split_once("_ex"))
+                .and_then(|(part, index)| Some((part.parse().ok()?, index.parse().ok()?)))
+            else {
+                continue;
+            };
+            let answer_path = path.with_extension("answer");
+            let answer = if answer_path.exists() {
+                Some(fs::read_to_string(&answer_path)?.trim().to_string())
+            } else {
+                None
+            };
+            examples.push(ExampleCase {
+                part,
+                index,
+                input: fs::read_to_string(&path)?,
+                answer,
+            });
+        }
+        examples.sort_by_key(|e| (e.part, e.index));
+        Ok(examples)
+    }
+
+    /// Replace the cached examples for a year/day
+    pub fn put_examples(
+        &self,
+        year: u16,
+        day: u8,
+        examples: &[ExampleCase],
+    ) -> Result<(), CacheError> {
+        // Remove stale examples first so a refresh does not leave old files behind
+        if self.user_dir.exists() {
+            let prefix = example_prefix(year, day);
+            for entry in fs::read_dir(&self.user_dir)? {
+                let path = entry?.path();
+                if path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with(&prefix))
+                {
+                    fs::remove_file(&path)?;
+                }
+            }
+        }
+
+        for example in examples {
+            let path = self.user_dir.join(format!(
+                "{}{}_ex{}.txt",
+                example_prefix(year, day),
+                example.part,
+                example.index
+            ));
+            self.write_atomic(&path, &example.input)?;
+            if let Some(answer) = &example.answer {
+                self.write_atomic(&path.with_extension("answer"), answer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the path of the confirmed answers file
+    pub fn answers_path(&self) -> PathBuf {
+        self.user_dir.join("answers.tsv")
+    }
+
+    /// Load the confirmed answers store (empty if none has been saved)
+    pub fn get_answers(&self) -> Result<AnswerStore, CacheError> {
+        let path = self.answers_path();
+        if path.exists() {
+            Ok(AnswerStore::parse(&fs::read_to_string(&path)?))
+        } else {
+            Ok(AnswerStore::default())
+        }
+    }
+
+    /// Save the confirmed answers store
+    pub fn put_answers(&self, answers: &AnswerStore) -> Result<(), CacheError> {
+        self.write_atomic(&self.answers_path(), &answers.to_tsv())
+    }
+
+    /// Get the path of the submission log
+    pub fn submissions_path(&self) -> PathBuf {
+        self.user_dir.join("submissions.tsv")
+    }
+
+    /// Load the submission log (empty if nothing has been submitted)
+    pub fn get_submissions(&self) -> Result<SubmissionLog, CacheError> {
+        let path = self.submissions_path();
+        if path.exists() {
+            Ok(SubmissionLog::parse(&fs::read_to_string(&path)?))
+        } else {
+            Ok(SubmissionLog::default())
+        }
+    }
+
+    /// Save the submission log
+    pub fn put_submissions(&self, log: &SubmissionLog) -> Result<(), CacheError> {
+        self.write_atomic(&self.submissions_path(), &log.to_tsv())
+    }
+
+    /// Get the cache path for a private leaderboard
+    pub fn leaderboard_path(&self, year: u16, id: u64) -> PathBuf {
+        self.user_dir
+            .join(format!("leaderboard_{}_{}.json", year, id))
+    }
+
+    /// Get a cached private leaderboard if it was stored less than `max_age` ago
+    ///
+    /// Unreadable or outdated cache files are treated as missing.
+    pub fn get_leaderboard(
+        &self,
+        year: u16,
+        id: u64,
+        max_age: Duration,
+    ) -> Result<Option<PrivateLeaderboard>, CacheError> {
+        let path = self.leaderboard_path(year, id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let age = fs::metadata(&path)?
+            .modified()?
+            .elapsed()
+            .unwrap_or(Duration::ZERO);
+        if age >= max_age {
+            return Ok(None);
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(&path)?).ok())
+    }
+
+    /// Store a private leaderboard, stamping it with the current time
+    pub fn put_leaderboard(
+        &self,
+        year: u16,
+        id: u64,
+        board: &PrivateLeaderboard,
+    ) -> Result<(), CacheError> {
+        let json = serde_json::to_string(board).map_err(std::io::Error::other)?;
+        self.write_atomic(&self.leaderboard_path(year, id), &json)
+    }
 
+    /// Write a file in the user directory through a temporary file and rename
+    fn write_atomic(&self, path: &Path, contents: &str) -> Result<(), CacheError> {
         // Create user directory if needed
         fs::create_dir_all(&self.user_dir).map_err(|e| {
             CacheError::DirCreation(format!(
@@ -59,11 +307,19 @@ impl InputCache {
             ))
         })?;
 
-        fs::write(&path, input)?;
+        // A temporary file of its own, so concurrent writers never share one
+        let mut tmp = tempfile::NamedTempFile::new_in(&self.user_dir)?;
+        tmp.write_all(contents.as_bytes())?;
+        tmp.persist(path).map_err(|e| e.error)?;
         Ok(())
     }
 }
 
+/// File name prefix shared by all example files of a year/day
+fn example_prefix(year: u16, day: u8) -> String {
+    format!("{}_day{:02}_p", year, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,5 +354,153 @@ mod tests {
         // Now cached
         assert!(cache.contains(2024, 1));
         assert_eq!(cache.get(2024, 1).unwrap(), Some(input.to_string()));
+        assert_eq!(
+            &cache.get_mapped(2024, 1).unwrap().unwrap()[..],
+            input.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_put_refuses_error_pages() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = InputCache::new(temp_dir.path().to_path_buf(), 12345);
+
+        let result = cache.put(
+            2024,
+            1,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        );
+        assert!(matches!(result, Err(CacheError::InvalidInput(_))));
+        assert!(!cache.contains(2024, 1));
+    }
+
+    #[test]
+    fn test_mapped_input_survives_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+        assert!(cache.get_mapped(2024, 1).unwrap().is_none());
+
+        cache.put(2024, 1, "first").unwrap();
+        let mapped = cache.get_mapped(2024, 1).unwrap().unwrap();
+
+        // Replacing the cached file must not change an existing mapping
+        cache.put(2024, 1, "second").unwrap();
+        assert_eq!(&mapped[..], b"first");
+
+        assert_eq!(&cache.get_mapped(2024, 1).unwrap().unwrap()[..], b"second");
+    }
+
+    #[test]
+    fn test_concurrent_puts_never_mix_contents() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+        let inputs: Vec<String> = (0..4).map(|i| i.to_string().repeat(100_000)).collect();
+
+        std::thread::scope(|s| {
+            for input in &inputs {
+                let cache = cache.clone();
+                s.spawn(move || {
+                    for _ in 0..10 {
+                        cache.put(2024, 1, input).unwrap();
+                    }
+                });
+            }
+        });
+
+        let stored = cache.get(2024, 1).unwrap().unwrap();
+        assert!(inputs.contains(&stored));
+        // No temporary files are left behind
+        let files = std::fs::read_dir(temp.path().join("12345"))
+            .unwrap()
+            .count();
+        assert_eq!(files, 1);
+    }
+
+    #[test]
+    fn test_puzzle_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+        assert!(cache.get_puzzle(2023, 5).unwrap().is_none());
+
+        cache.put_puzzle(2023, 5, "# 2023 Day 5\n").unwrap();
+        assert_eq!(
+            cache.get_puzzle(2023, 5).unwrap().as_deref(),
+            Some("# 2023 Day 5\n")
+        );
+        // Descriptions do not count as cached inputs
+        assert!(!cache.contains(2023, 5));
+    }
+
+    #[test]
+    fn test_examples_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+        assert!(cache.get_examples(2023, 1).unwrap().is_empty());
+
+        let examples = vec![
+            ExampleCase {
+                part: 1,
+                index: 1,
+                input: "1abc2\n".to_string(),
+                answer: Some("12".to_string()),
+            },
+            ExampleCase {
+                part: 2,
+                index: 1,
+                input: "two1nine\n".to_string(),
+                answer: None,
+            },
+        ];
+        cache.put_examples(2023, 1, &examples).unwrap();
+        cache.put(2023, 1, "real input").unwrap();
+        assert_eq!(cache.get_examples(2023, 1).unwrap(), examples);
+
+        // Replacing drops stale examples but keeps the real input
+        cache.put_examples(2023, 1, &examples[..1]).unwrap();
+        assert_eq!(cache.get_examples(2023, 1).unwrap(), examples[..1]);
+        assert!(cache.contains(2023, 1));
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+        assert_eq!(cache.get_answers().unwrap().len(), 0);
+
+        let mut answers = AnswerStore::default();
+        answers.insert(2023, 5, 1, "35");
+        cache.put_answers(&answers).unwrap();
+        assert_eq!(cache.get_answers().unwrap(), answers);
+    }
+
+    #[test]
+    fn test_leaderboard_expires() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+        let board: PrivateLeaderboard = serde_json::from_str(
+            r#"{"event":"2023","owner_id":7,"members":{"7":{"id":7,"name":null,"stars":0,"local_score":0}}}"#,
+        )
+        .unwrap();
+        let hour = Duration::from_secs(3600);
+        assert!(cache.get_leaderboard(2023, 7, hour).unwrap().is_none());
+
+        cache.put_leaderboard(2023, 7, &board).unwrap();
+        assert_eq!(cache.get_leaderboard(2023, 7, hour).unwrap(), Some(board));
+        assert!(
+            cache
+                .get_leaderboard(2023, 7, Duration::ZERO)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_mapped_empty_input() {
+        let temp = TempDir::new().unwrap();
+        let cache = InputCache::new(temp.path().to_path_buf(), 12345);
+
+        cache.put(2024, 2, "").unwrap();
+        let data = InputData::Mapped(cache.get_mapped(2024, 2).unwrap().unwrap());
+        assert!(data.is_empty());
     }
 }
@@ -1,7 +1,10 @@
 //! CLI argument parsing using clap
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fmt;
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 /// Parallelization level for solver execution
 #[derive(Debug, Clone, Copy, Default, ValueEnum, PartialEq, Eq)]
@@ -17,51 +20,470 @@ pub enum ParallelizeBy {
     Part,
 }
 
+/// A single puzzle day written as `YEAR/DAY`, e.g. `2023/5`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaySelector {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s
+            .split_once('/')
+            .ok_or_else(|| format!("expected YEAR/DAY (e.g. 2023/5), got '{}'", s))?;
+        let year: u16 = year
+            .parse()
+            .map_err(|_| format!("invalid year '{}'", year))?;
+        let day: u8 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+        if year < 2015 {
+            return Err(format!("year must be 2015 or later, got {}", year));
+        }
+        if !(1..=25).contains(&day) {
+            return Err(format!("day must be between 1 and 25, got {}", day));
+        }
+        Ok(Self { year, day })
+    }
+}
+
+impl fmt::Display for DaySelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/// A single puzzle part written as `YEAR/DAY/PART`, e.g. `2023/5/2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartSelector {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+}
+
+impl FromStr for PartSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, part) = s
+            .rsplit_once('/')
+            .filter(|(day, _)| day.contains('/'))
+            .ok_or_else(|| format!("expected YEAR/DAY/PART (e.g. 2023/5/1), got '{}'", s))?;
+        let DaySelector { year, day } = day.parse()?;
+        let part = match part.parse() {
+            Ok(part @ 1..=2) => part,
+            _ => return Err(format!("part must be 1 or 2, got '{}'", part)),
+        };
+        Ok(Self { year, day, part })
+    }
+}
+
+impl fmt::Display for PartSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.year, self.day, self.part)
+    }
+}
+
+/// Subcommands; `run` is the default when none is given
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Run the solvers selected by --year, --day, --part and --tags
+    Run,
+    /// Download and cache inputs without running any solver
+    Fetch {
+        /// Puzzles to fetch, as YEAR/DAY (default: those of the selected solvers)
+        puzzles: Vec<DaySelector>,
+    },
+    /// Submit an answer by hand
+    Submit {
+        /// Part to answer, as YEAR/DAY/PART (e.g. 2023/5/1)
+        puzzle: PartSelector,
+
+        /// The answer to submit
+        answer: String,
+    },
+    /// List the selected solvers with their cached inputs and confirmed answers
+    List,
+    /// Show or edit the store of answers confirmed by AoC
+    Answers {
+        #[command(subcommand)]
+        action: AnswersCommand,
+    },
+    /// Print a puzzle description and store it in the cache
+    Read {
+        /// Puzzle to read, as YEAR/DAY (e.g. 2023/5)
+        puzzle: DaySelector,
+
+        /// Fetch the puzzle again even if it is cached (e.g. after solving part 1)
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Save the example inputs from a puzzle description and run the solver on them
+    Examples {
+        /// Puzzle to check, as YEAR/DAY (e.g. 2023/5)
+        puzzle: DaySelector,
+
+        /// Extract the examples again even if they are cached
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Generate a solver module for a new day from a template
+    New {
+        /// Puzzle to scaffold, as YEAR/DAY (e.g. 2024/5)
+        puzzle: DaySelector,
+
+        /// Solutions crate to add the solver to
+        #[arg(long, default_value = "aoc-solutions")]
+        solutions_dir: PathBuf,
+
+        /// Directory holding a custom `day.rs.tmpl` (default: `AOC_TEMPLATE_DIR`, then the built-in template)
+        #[arg(long)]
+        template_dir: Option<PathBuf>,
+
+        /// Also fetch and cache the input and the puzzle's examples
+        #[arg(long)]
+        fetch: bool,
+
+        /// Overwrite the solver module if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Old name of `answers sync`, kept so existing scripts still work
+    #[command(hide = true)]
+    SyncAnswers {
+        /// Year to sync
+        year: u16,
+
+        /// Days to sync, as a single day or a range like 1-10 (default: all)
+        #[arg(long, value_parser = parse_day_range, default_value = "1-25")]
+        days: RangeInclusive<u8>,
+    },
+    /// Show private leaderboard standings and solve times for --day (default: latest day with stars)
+    Leaderboard {
+        /// Event year
+        year: u16,
+
+        /// Leaderboard ID (the owner's user ID)
+        id: u64,
+    },
+    /// Count down to a puzzle's unlock, then fetch and cache its input
+    Wait {
+        /// Puzzle to wait for, as YEAR/DAY (e.g. 2025/3)
+        puzzle: DaySelector,
+
+        /// Run the day's solver once the input is cached
+        #[arg(long)]
+        solve: bool,
+    },
+    /// Print a grid of collected stars for each year
+    Calendar {
+        /// Years to show
+        #[arg(required = true)]
+        years: Vec<u16>,
+    },
+    /// Save a session in the encrypted session store under the selected profile
+    Login,
+    /// Remove the selected profile from the session store
+    Logout {
+        /// Delete the whole store instead of one profile
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+/// Actions on the confirmed answers store
+#[derive(Subcommand, Debug, Clone)]
+pub enum AnswersCommand {
+    /// Print the confirmed answers selected by --year, --day and --part
+    List,
+    /// Record an answer as confirmed
+    Set {
+        /// Part the answer belongs to, as YEAR/DAY/PART
+        puzzle: PartSelector,
+
+        /// The confirmed answer
+        answer: String,
+    },
+    /// Forget a confirmed answer
+    Remove {
+        /// Part to forget, as YEAR/DAY/PART
+        puzzle: PartSelector,
+    },
+    /// Record the answers AoC has accepted for a year
+    Sync {
+        /// Year to sync
+        year: u16,
+
+        /// Days to sync, as a single day or a range like 1-10 (default: all)
+        #[arg(long, value_parser = parse_day_range, default_value = "1-25")]
+        days: RangeInclusive<u8>,
+    },
+}
+
+/// Parse a day or day range such as `5` or `1-10`
+fn parse_day_range(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |d: &str| -> Result<u8, String> {
+        match d.trim().parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("day must be between 1 and 25, got '{}'", d)),
+        }
+    };
+    let range = match s.split_once('-') {
+        Some((start, end)) => parse_day(start)?..=parse_day(end)?,
+        None => parse_day(s)?..=parse_day(s)?,
+    };
+    if range.is_empty() {
+        return Err(format!("empty day range '{}'", s));
+    }
+    Ok(range)
+}
+
 /// Advent of Code solver runner
 #[derive(Parser, Debug)]
 #[command(name = "aoc", about = "Run Advent of Code solvers", version)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Year to run (runs all years if omitted)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub year: Option<u16>,
 
     /// Day to run (runs all days if omitted)
-    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u8).range(1..=25))]
     pub day: Option<u8>,
 
     /// Part to run (runs all parts if omitted)
-    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..))]
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u8).range(1..))]
     pub part: Option<u8>,
 
     /// Tags to filter solvers (comma-separated)
-    #[arg(short, long, value_delimiter = ',')]
+    #[arg(short, long, global = true, value_delimiter = ',')]
     pub tags: Vec<String>,
 
     /// Cache directory for puzzle inputs
-    #[arg(long, default_value = "~/.cache/aoc_solver")]
+    #[arg(long, global = true, default_value = "~/.cache/aoc_solver")]
     pub cache_dir: PathBuf,
 
     /// Number of threads for parallel execution
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub threads: Option<usize>,
 
     /// Parallelization level: sequential, year, day, or part
-    #[arg(long, value_enum, default_value = "day")]
+    #[arg(long, global = true, value_enum, default_value = "day")]
     pub parallelize_by: ParallelizeBy,
 
     /// Submit answers to Advent of Code
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub submit: bool,
 
     /// User ID for cache organization and verification
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub user_id: Option<u64>,
 
     /// Auto-retry on throttle with parsed wait time
-    #[arg(long, default_value = "false")]
+    #[arg(long, global = true, default_value = "false")]
     pub auto_retry: bool,
 
+    /// Contact (e.g. your email) sent to adventofcode.com in the User-Agent header
+    #[arg(long, global = true)]
+    pub contact: Option<String>,
+
+    /// Server to use instead of adventofcode.com, e.g. a local aoc-mock-server (default: `AOC_BASE_URL`)
+    #[arg(long, global = true)]
+    pub base_url: Option<String>,
+
+    /// Session store profile to use (default: `AOC_PROFILE`, then "default")
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
+    /// Unlock the session store with this file instead of a passphrase (default: `AOC_KEY_FILE`)
+    #[arg(long, global = true)]
+    pub key_file: Option<PathBuf>,
+
+    /// Never prompt for input; fail instead (implied when stdin is not a terminal)
+    #[arg(long, global = true)]
+    pub non_interactive: bool,
+
     /// Quiet mode - only output answers
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub quiet: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selector_parse() {
+        assert_eq!(
+            "2023/5".parse::<DaySelector>(),
+            Ok(DaySelector { year: 2023, day: 5 })
+        );
+        assert_eq!(
+            "2015/01".parse::<DaySelector>(),
+            Ok(DaySelector { year: 2015, day: 1 })
+        );
+        assert!("2023".parse::<DaySelector>().is_err());
+        assert!("2023/26".parse::<DaySelector>().is_err());
+        assert!("2014/1".parse::<DaySelector>().is_err());
+        assert!("abc/1".parse::<DaySelector>().is_err());
+    }
+
+    #[test]
+    fn test_parse_day_range() {
+        assert_eq!(parse_day_range("5"), Ok(5..=5));
+        assert_eq!(parse_day_range("1-10"), Ok(1..=10));
+        assert!(parse_day_range("10-1").is_err());
+        assert!(parse_day_range("0-3").is_err());
+        assert!(parse_day_range("x").is_err());
+    }
+
+    #[test]
+    fn test_read_subcommand() {
+        let args = Args::try_parse_from(["aoc", "read", "2023/5"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Read {
+                puzzle: DaySelector { year: 2023, day: 5 },
+                refresh: false
+            })
+        ));
+
+        let args = Args::try_parse_from(["aoc", "--year", "2023"]).unwrap();
+        assert!(args.command.is_none());
+    }
+
+    #[test]
+    fn test_wait_subcommand() {
+        let args = Args::try_parse_from(["aoc", "wait", "2025/3", "--solve"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Wait {
+                puzzle: DaySelector { year: 2025, day: 3 },
+                solve: true
+            })
+        ));
+    }
+
+    #[test]
+    fn test_non_interactive_flag() {
+        let args = Args::try_parse_from(["aoc", "--non-interactive"]).unwrap();
+        assert!(args.non_interactive);
+        assert!(!Args::try_parse_from(["aoc"]).unwrap().non_interactive);
+    }
+
+    #[test]
+    fn test_base_url_option() {
+        let args = Args::try_parse_from(["aoc", "--base-url", "http://127.0.0.1:3000"]).unwrap();
+        assert_eq!(args.base_url.as_deref(), Some("http://127.0.0.1:3000"));
+        assert!(Args::try_parse_from(["aoc"]).unwrap().base_url.is_none());
+    }
+
+    #[test]
+    fn test_login_and_logout_subcommands() {
+        let args = Args::try_parse_from(["aoc", "--profile", "work", "login"]).unwrap();
+        assert!(matches!(args.command, Some(Command::Login)));
+        assert_eq!(args.profile.as_deref(), Some("work"));
+
+        let args = Args::try_parse_from(["aoc", "logout", "--all"]).unwrap();
+        assert!(matches!(args.command, Some(Command::Logout { all: true })));
+    }
+
+    #[test]
+    fn test_new_subcommand() {
+        let args = Args::try_parse_from(["aoc", "new", "2024/5", "--fetch"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::New {
+                puzzle: DaySelector { year: 2024, day: 5 },
+                template_dir: None,
+                fetch: true,
+                force: false,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_selector_parse() {
+        assert_eq!(
+            "2023/5/2".parse::<PartSelector>(),
+            Ok(PartSelector {
+                year: 2023,
+                day: 5,
+                part: 2
+            })
+        );
+        assert!("2023/5".parse::<PartSelector>().is_err());
+        assert!("2023/5/3".parse::<PartSelector>().is_err());
+        assert!("2023/26/1".parse::<PartSelector>().is_err());
+    }
+
+    #[test]
+    fn test_old_command_lines_still_parse() {
+        let args = Args::try_parse_from(["aoc", "sync-answers", "2023", "--days", "1-3"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::SyncAnswers { year: 2023, days }) if days == (1..=3)
+        ));
+
+        let args = Args::try_parse_from(["aoc", "leaderboard", "2023", "7", "--day", "3"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Leaderboard { year: 2023, id: 7 })
+        ));
+        assert_eq!(args.day, Some(3));
+    }
+
+    #[test]
+    fn test_run_subcommand_takes_global_flags() {
+        let args = Args::try_parse_from(["aoc", "run", "--year", "2023"]).unwrap();
+        assert!(matches!(args.command, Some(Command::Run)));
+        assert_eq!(args.year, Some(2023));
+    }
+
+    #[test]
+    fn test_fetch_and_submit_subcommands() {
+        let args = Args::try_parse_from(["aoc", "fetch", "2023/1", "2023/2"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Fetch { puzzles }) if puzzles.len() == 2
+        ));
+
+        let args = Args::try_parse_from(["aoc", "submit", "2023/5/1", "42"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Submit {
+                puzzle: PartSelector {
+                    year: 2023,
+                    day: 5,
+                    part: 1
+                },
+                answer
+            }) if answer == "42"
+        ));
+    }
+
+    #[test]
+    fn test_answers_subcommands() {
+        let args = Args::try_parse_from(["aoc", "answers", "list", "-y", "2023"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Answers {
+                action: AnswersCommand::List
+            })
+        ));
+        assert_eq!(args.year, Some(2023));
+
+        let args =
+            Args::try_parse_from(["aoc", "answers", "sync", "2023", "--days", "1-3"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Answers {
+                action: AnswersCommand::Sync { year: 2023, days }
+            }) if days == (1..=3)
+        ));
+    }
+}
@@ -0,0 +1,620 @@
+//! Handlers for CLI subcommands other than running solvers
+
+use crate::cache::{ExampleCase, InputCache};
+use crate::cli::{DaySelector, PartSelector};
+use crate::config::{self, ClientOptions, Config, StoreOptions};
+use crate::error::{ArcExecutorError, CliError, ExecutorError};
+use crate::executor::Executor;
+use crate::output;
+use crate::scaffold::{self, TemplateVars};
+use crate::session_store::{Profile, SessionStore};
+use aoc_http_client::{AocError, Clock, LEADERBOARD_REFRESH_INTERVAL, Puzzle, time_until_unlock};
+use aoc_solver::SolverRegistry;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+use zeroize::Zeroizing;
+
+/// Print a puzzle description, fetching and caching it if needed
+pub fn read(config: &Config, puzzle: DaySelector, refresh: bool) -> Result<(), CliError> {
+    let DaySelector { year, day } = puzzle;
+    let cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+
+    if !refresh && let Some(markdown) = cache.get_puzzle(year, day)? {
+        print!("{}", markdown);
+        return Ok(());
+    }
+
+    let (page, _) = fetch_puzzle(config, puzzle)?;
+    print!("{}", page.to_markdown());
+    Ok(())
+}
+
+/// Save the examples from a puzzle description and check the solver against them
+pub fn examples(
+    config: &Config,
+    registry: &SolverRegistry,
+    puzzle: DaySelector,
+    refresh: bool,
+) -> Result<(), CliError> {
+    let DaySelector { year, day } = puzzle;
+    let mut cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+
+    let mut examples = if refresh {
+        Vec::new()
+    } else {
+        cache.get_examples(year, day)?
+    };
+    if examples.is_empty() {
+        let (page, fetch_cache) = fetch_puzzle(config, puzzle)?;
+        cache = fetch_cache;
+        examples = example_cases(&page);
+        cache.put_examples(year, day, &examples)?;
+        println!("Saved {} example(s) for {}", examples.len(), puzzle);
+    }
+
+    if examples.is_empty() {
+        println!("No examples found in the puzzle description.");
+        return Ok(());
+    }
+    if !registry.storage().contains(year, day) {
+        println!(
+            "No solver registered for {}; examples were saved only.",
+            puzzle
+        );
+        return Ok(());
+    }
+
+    let mut checked = 0;
+    let mut matched = 0;
+    for example in &examples {
+        let label = format!(
+            "{}/{:02} Part {} example {}",
+            year, day, example.part, example.index
+        );
+        let answer = registry
+            .create_solver(year, day, &example.input)
+            .and_then(|mut solver| Ok(solver.solve(example.part)?.answer));
+        match (answer, &example.answer) {
+            (Ok(actual), Some(expected)) => {
+                checked += 1;
+                if actual == *expected {
+                    matched += 1;
+                    println!("{}: {} ✓", label, actual);
+                } else {
+                    println!("{}: {} ✗ (expected {})", label, actual, expected);
+                }
+            }
+            (Ok(actual), None) => println!("{}: {} (no expected answer)", label, actual),
+            (Err(e), _) => {
+                checked += usize::from(example.answer.is_some());
+                eprintln!("{}: Error - {}", label, e);
+            }
+        }
+    }
+
+    println!();
+    println!("Examples: {}/{} matched", matched, checked);
+    Ok(())
+}
+
+/// Fetch and cache the inputs of the given puzzles, skipping cached ones
+///
+/// A failed day is reported and the rest are still fetched. Days blocked by a
+/// rejected session are retried after prompting for a new one, as in a run.
+pub fn fetch(
+    executor: &mut Executor,
+    config: &Config,
+    puzzles: &[DaySelector],
+) -> Result<(), CliError> {
+    let mut fetched = 0;
+    let mut first_error = None;
+    let mut pending = puzzles.to_vec();
+    while !pending.is_empty() {
+        let mut expired = Vec::new();
+        for puzzle in pending {
+            match executor.fetch_input(puzzle.year, puzzle.day) {
+                Ok(true) => {
+                    fetched += 1;
+                    println!("Fetched input for {}", puzzle);
+                }
+                Ok(false) => println!("Input for {} is already cached.", puzzle),
+                Err(ExecutorError::SessionExpired) => expired.push(puzzle),
+                Err(e) => {
+                    eprintln!("Failed to fetch input for {}: {}", puzzle, e);
+                    first_error.get_or_insert(ArcExecutorError::from(e).into());
+                }
+            }
+        }
+        if expired.is_empty() {
+            break;
+        }
+
+        let error = if config.interactive {
+            match crate::reauthenticate(executor, &config.client_options, expired.len()) {
+                Ok(()) => {
+                    pending = expired;
+                    continue;
+                }
+                Err(e) => e,
+            }
+        } else {
+            CliError::SessionExpired
+        };
+        for puzzle in &expired {
+            eprintln!("Not fetched: input for {} (session expired)", puzzle);
+        }
+        first_error = Some(error);
+        break;
+    }
+    println!(
+        "Fetched {} of {} input(s) for user {}",
+        fetched,
+        puzzles.len(),
+        executor.user_id()
+    );
+    first_error.map_or(Ok(()), Err)
+}
+
+/// List the selected solvers with their cached inputs and confirmed answers
+pub fn list(executor: &Executor) -> Result<(), CliError> {
+    let work_items = executor.collect_work_items();
+    if work_items.is_empty() {
+        println!("No solvers found matching the specified filters.");
+        return Ok(());
+    }
+
+    let cache = executor.cache();
+    let answers = cache.get_answers()?;
+    for work in &work_items {
+        let confirmed = work
+            .parts
+            .clone()
+            .filter(|&part| answers.get(work.year, work.day, part).is_some())
+            .count();
+        println!(
+            "{}/{:02}  parts {}-{}  input {}  {}/{} confirmed",
+            work.year,
+            work.day,
+            work.parts.start(),
+            work.parts.end(),
+            if cache.contains(work.year, work.day) {
+                "cached "
+            } else {
+                "missing"
+            },
+            confirmed,
+            work.parts.clone().count()
+        );
+    }
+    Ok(())
+}
+
+/// Print the confirmed answers matching the year, day and part filters
+pub fn answers_list(config: &Config) -> Result<(), CliError> {
+    let cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+    let store = cache.get_answers()?;
+    let mut shown = 0;
+    for ((year, day, part), answer) in store.iter() {
+        if config.year_filter.is_some_and(|y| y != year)
+            || config.day_filter.is_some_and(|d| d != day)
+            || config.part_filter.is_some_and(|p| p != part)
+        {
+            continue;
+        }
+        shown += 1;
+        println!("{}/{:02} Part {}: {}", year, day, part, answer);
+    }
+    if shown == 0 {
+        println!("No confirmed answers stored.");
+    }
+    Ok(())
+}
+
+/// Record an answer as confirmed, replacing any earlier one
+pub fn answers_set(config: &Config, puzzle: PartSelector, answer: &str) -> Result<(), CliError> {
+    let PartSelector { year, day, part } = puzzle;
+    check_storable_answer(answer)?;
+    let cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+    let mut store = cache.get_answers()?;
+    let previous = store.get(year, day, part).map(str::to_string);
+    store.insert(year, day, part, answer);
+    cache.put_answers(&store)?;
+    match previous {
+        Some(previous) if previous != answer => {
+            println!("Replaced answer for {}: {} -> {}", puzzle, previous, answer)
+        }
+        _ => println!("Recorded answer for {}: {}", puzzle, answer),
+    }
+    Ok(())
+}
+
+/// Reject answers that would break the tab-separated answers file
+fn check_storable_answer(answer: &str) -> Result<(), CliError> {
+    if answer.trim().is_empty() || answer.contains(['\t', '\n', '\r']) {
+        return Err(CliError::Config(format!(
+            "invalid answer {:?}: must be non-empty without tabs or line breaks",
+            answer
+        )));
+    }
+    Ok(())
+}
+
+/// Forget a confirmed answer
+pub fn answers_remove(config: &Config, puzzle: PartSelector) -> Result<(), CliError> {
+    let PartSelector { year, day, part } = puzzle;
+    let cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+    let mut store = cache.get_answers()?;
+    match store.remove(year, day, part) {
+        Some(answer) => {
+            cache.put_answers(&store)?;
+            println!("Removed answer for {}: {}", puzzle, answer);
+        }
+        None => println!("No confirmed answer is stored for {}.", puzzle),
+    }
+    Ok(())
+}
+
+/// Scrape confirmed answers from AoC and merge them into the answers store
+pub fn sync_answers(config: &Config, year: u16, days: RangeInclusive<u8>) -> Result<(), CliError> {
+    let (session, cache) = session_and_cache(config, "sync confirmed answers")?;
+
+    let client = config.client()?;
+    let fetched = client.get_answers(year, days, &session)?;
+
+    let mut store = cache.get_answers()?;
+    let mut total = 0;
+    let mut added = 0;
+    for day in &fetched {
+        for (part, answer) in (1u8..).zip(&day.answers) {
+            total += 1;
+            added += usize::from(store.insert(day.year, day.day, part, answer.as_str()));
+        }
+    }
+    cache.put_answers(&store)?;
+
+    println!(
+        "Synced {} answer(s) for {} day(s) of {} ({} new or changed, {} stored in total)",
+        total,
+        fetched.len(),
+        year,
+        added,
+        store.len()
+    );
+    Ok(())
+}
+
+/// Options for [`new_day`]
+#[derive(Debug, Clone)]
+pub struct NewOptions {
+    /// Solutions crate to add the solver to
+    pub solutions_dir: PathBuf,
+    /// Directory holding a custom template; falls back to `AOC_TEMPLATE_DIR`
+    pub template_dir: Option<PathBuf>,
+    /// Fetch and cache the input and examples first
+    pub fetch: bool,
+    /// Overwrite an existing solver module
+    pub force: bool,
+}
+
+/// Generate a solver module for a puzzle from a template
+///
+/// The first part 1 example, if cached or fetched, becomes the template's example test.
+pub fn new_day(config: &Config, puzzle: DaySelector, options: &NewOptions) -> Result<(), CliError> {
+    let DaySelector { year, day } = puzzle;
+    let template_dir = options
+        .template_dir
+        .clone()
+        .or_else(|| std::env::var_os("AOC_TEMPLATE_DIR").map(PathBuf::from));
+    let template = scaffold::load_template(template_dir.as_deref())?;
+
+    let mut cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+    let mut examples = cache.get_examples(year, day)?;
+    if options.fetch {
+        let (session, fetch_cache) = session_and_cache(config, "fetch the input and examples")?;
+        cache = fetch_cache;
+        let client = config.client()?;
+        if cache.contains(year, day) {
+            println!("Input for {} is already cached.", puzzle);
+        } else {
+            let input = client.get_input(year, day, &session)?;
+            cache.put(year, day, &input)?;
+            println!("Cached input for {} ({} bytes)", puzzle, input.len());
+        }
+        if examples.is_empty() {
+            let page = client.get_puzzle(year, day, &session)?;
+            if let Err(e) = cache.put_puzzle(year, day, &page.to_markdown()) {
+                eprintln!("Warning: failed to cache puzzle {}: {}", puzzle, e);
+            }
+            examples = example_cases(&page);
+            cache.put_examples(year, day, &examples)?;
+            println!("Saved {} example(s) for {}", examples.len(), puzzle);
+        }
+    }
+
+    let example = examples
+        .iter()
+        .filter(|e| e.part == 1)
+        .min_by_key(|e| e.answer.is_none());
+    let vars = TemplateVars {
+        year,
+        day,
+        example_input: example.map_or("", |e| e.input.as_str()),
+        example_answer: example.and_then(|e| e.answer.as_deref()).unwrap_or(""),
+    };
+    let source = scaffold::render(&template, &vars)?;
+    let scaffolded = scaffold::scaffold(&options.solutions_dir, &source, year, day, options.force)?;
+
+    println!("Created {}", scaffolded.solver.display());
+    for path in &scaffolded.updated {
+        println!("Updated {}", path.display());
+    }
+    Ok(())
+}
+
+/// Print private leaderboard standings and solve times for one day
+///
+/// Leaderboards are cached on disk and refetched at most once per
+/// [`LEADERBOARD_REFRESH_INTERVAL`], as AoC requests.
+pub fn leaderboard(config: &Config, year: u16, id: u64, day: Option<u8>) -> Result<(), CliError> {
+    let cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+    let board = match cache.get_leaderboard(year, id, LEADERBOARD_REFRESH_INTERVAL)? {
+        Some(board) => board,
+        None => {
+            let (session, cache) = session_and_cache(config, "view private leaderboards")?;
+            let board = config
+                .client()?
+                .get_private_leaderboard(year, id, &session)?;
+            if let Err(e) = cache.put_leaderboard(year, id, &board) {
+                eprintln!("Warning: failed to cache leaderboard: {}", e);
+            }
+            board
+        }
+    };
+
+    println!("Private leaderboard {} ({})", id, board.event);
+    print!("{}", output::format_standings(&board));
+
+    let latest_day = board
+        .members
+        .values()
+        .flat_map(|m| m.completion_day_level.keys().copied())
+        .max();
+    if let Some(day) = day.or(latest_day) {
+        println!();
+        print!("{}", output::format_day_deltas(&board, year, day));
+    }
+    Ok(())
+}
+
+/// Print the star grid for each requested year
+pub fn calendar(config: &Config, years: &[u16]) -> Result<(), CliError> {
+    let (session, _) = session_and_cache(config, "read calendar progress")?;
+    let client = config.client()?;
+    for (i, &year) in years.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print!(
+            "{}",
+            output::format_calendar(&client.get_calendar(year, &session)?)
+        );
+    }
+    Ok(())
+}
+
+/// Number of times a locked or missing input is retried right after unlock, one second apart
+const UNLOCK_FETCH_ATTEMPTS: u32 = 10;
+
+/// Wait for a puzzle to unlock, then fetch and cache its input
+pub fn wait(config: &Config, puzzle: DaySelector, clock: &impl Clock) -> Result<(), CliError> {
+    let DaySelector { year, day } = puzzle;
+    let (session, cache) = session_and_cache(config, "fetch the input when it unlocks")?;
+    if cache.contains(year, day) {
+        println!("Input for {} is already cached.", puzzle);
+        return Ok(());
+    }
+
+    wait_for_unlock(year, day, clock, |remaining| {
+        print!(
+            "\r{} unlocks in {} ",
+            puzzle,
+            output::format_countdown(remaining)
+        );
+        std::io::stdout().flush().ok();
+    });
+    println!("\r{} is unlocked, fetching input...", puzzle);
+
+    // The input can lag the unlock by a moment, showing up as not yet unlocked
+    let client = config.client()?;
+    let mut attempt = 1;
+    let input = loop {
+        match client.get_input(year, day, &session) {
+            Err(AocError::NotYetUnlocked | AocError::PuzzleNotFound)
+                if attempt < UNLOCK_FETCH_ATTEMPTS =>
+            {
+                clock.sleep(Duration::from_secs(1));
+                attempt += 1;
+            }
+            result => break result?,
+        }
+    };
+    cache.put(year, day, &input)?;
+    println!("Cached input for {} ({} bytes)", puzzle, input.len());
+    Ok(())
+}
+
+/// Sleep on `clock` until the puzzle unlocks, calling `tick` with the time left
+///
+/// Wakes at least once a second so a countdown can be shown.
+fn wait_for_unlock(year: u16, day: u8, clock: &impl Clock, mut tick: impl FnMut(Duration)) {
+    while let Some(remaining) = time_until_unlock(year, day, clock) {
+        tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Fetch a puzzle page and cache its Markdown description
+///
+/// Returns the cache for the user the page was fetched as, which differs from
+/// the configured one if a session had to be prompted for.
+fn fetch_puzzle(config: &Config, puzzle: DaySelector) -> Result<(Puzzle, InputCache), CliError> {
+    let DaySelector { year, day } = puzzle;
+    let (session, cache) = session_and_cache(config, "fetch puzzle descriptions")?;
+
+    let client = config.client()?;
+    let page = client.get_puzzle(year, day, &session)?;
+
+    // Cache the description (warn on failure, don't fail the operation)
+    if let Err(e) = cache.put_puzzle(year, day, &page.to_markdown()) {
+        eprintln!("Warning: failed to cache puzzle {}: {}", puzzle, e);
+    }
+    Ok((page, cache))
+}
+
+/// Verify a session and save it in the session store under the selected profile
+///
+/// Takes the session from `AOC_SESSION` or prompts for it.
+pub fn login(
+    store: &StoreOptions,
+    client_options: &ClientOptions,
+    user_id: Option<u64>,
+) -> Result<(), CliError> {
+    let key = store.key(true)?;
+    let mut sessions = SessionStore::load(&store.path, &key)?;
+
+    let session = match std::env::var("AOC_SESSION") {
+        Ok(session) => Zeroizing::new(session),
+        Err(_) => config::prompt_session(&format!(
+            "Session token to save as profile '{}'",
+            store.profile
+        ))?,
+    };
+    let user_id = config::verify_session(client_options, &session, user_id)?;
+
+    sessions.insert(&store.profile, Profile { session, user_id })?;
+    sessions.save(&store.path, &key)?;
+    println!(
+        "Saved session for user {} as profile '{}'",
+        user_id, store.profile
+    );
+    Ok(())
+}
+
+/// Remove the selected profile from the session store, or the whole store with `all`
+pub fn logout(store: &StoreOptions, all: bool) -> Result<(), CliError> {
+    if !store.path.exists() {
+        println!("No sessions are saved.");
+        return Ok(());
+    }
+    if all {
+        std::fs::remove_file(&store.path)?;
+        println!("Removed all saved sessions.");
+        return Ok(());
+    }
+
+    let key = store.key(false)?;
+    let mut sessions = SessionStore::load(&store.path, &key)?;
+    if sessions.remove(&store.profile).is_none() {
+        println!("No session is saved as profile '{}'.", store.profile);
+        return Ok(());
+    }
+    if sessions.is_empty() {
+        std::fs::remove_file(&store.path)?;
+    } else {
+        sessions.save(&store.path, &key)?;
+    }
+    println!("Removed profile '{}'.", store.profile);
+    Ok(())
+}
+
+/// Get the configured or saved session, prompting for one if needed, and the matching cache
+fn session_and_cache(
+    config: &Config,
+    purpose: &str,
+) -> Result<(Zeroizing<String>, InputCache), CliError> {
+    if !config.session.is_empty() {
+        let cache = InputCache::new(config.cache_dir.clone(), config.user_id);
+        return Ok((config.session.clone(), cache));
+    }
+    if let Some((session, user_id)) = config.stored_session()? {
+        return Ok((session, InputCache::new(config.cache_dir.clone(), user_id)));
+    }
+    let session = config::prompt_session(&format!("Session token required to {}", purpose))?;
+    let expected = config.user_id_provided.then_some(config.user_id);
+    let user_id = config::verify_session(&config.client_options, &session, expected)?;
+    Ok((session, InputCache::new(config.cache_dir.clone(), user_id)))
+}
+
+/// Flatten the examples of every part into numbered cache entries
+fn example_cases(puzzle: &Puzzle) -> Vec<ExampleCase> {
+    (1u8..)
+        .zip(&puzzle.parts)
+        .flat_map(|(part, p)| {
+            (1..).zip(&p.examples).map(move |(index, e)| ExampleCase {
+                part,
+                index,
+                input: e.input.clone(),
+                answer: e.answer.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Clock that only moves when slept on
+    struct FakeClock(Cell<SystemTime>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    #[test]
+    fn test_check_storable_answer() {
+        assert!(check_storable_answer("142").is_ok());
+        assert!(check_storable_answer("ab,cd").is_ok());
+        assert!(check_storable_answer("").is_err());
+        assert!(check_storable_answer("1\t2").is_err());
+        assert!(check_storable_answer("12\n").is_err());
+    }
+
+    #[test]
+    fn test_wait_for_unlock_counts_down() {
+        // 2.5 seconds before 2023-12-01 05:00:00 UTC
+        let start = UNIX_EPOCH + Duration::from_millis(1_701_406_797_500);
+        let clock = FakeClock(Cell::new(start));
+        let mut ticks = Vec::new();
+
+        wait_for_unlock(2023, 1, &clock, |remaining| ticks.push(remaining));
+
+        assert_eq!(
+            ticks,
+            [
+                Duration::from_millis(2500),
+                Duration::from_millis(1500),
+                Duration::from_millis(500)
+            ]
+        );
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(1_701_406_800));
+    }
+
+    #[test]
+    fn test_wait_for_unlock_returns_when_unlocked() {
+        let clock = FakeClock(Cell::new(UNIX_EPOCH + Duration::from_secs(1_701_406_800)));
+        let mut ticks = 0;
+        wait_for_unlock(2023, 1, &clock, |_| ticks += 1);
+        assert_eq!(ticks, 0);
+    }
+}
@@ -2,9 +2,18 @@
 
 use crate::cli::{Args, ParallelizeBy};
 use crate::error::CliError;
+use crate::session_store::{
+    DEFAULT_PROFILE, Profile, STORE_FILE, SessionStore, StoreKey, create_key_file,
+};
+use aoc_http_client::AocClient;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use zeroize::Zeroizing;
 
+/// Minimum time between two requests to adventofcode.com (not applied to other servers)
+const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Resolved runtime configuration
 pub struct Config {
     /// Year filter (None = all years)
@@ -33,6 +42,12 @@ pub struct Config {
     pub auto_retry: bool,
     /// Quiet mode
     pub quiet: bool,
+    /// Contact and server used by HTTP clients
+    pub client_options: ClientOptions,
+    /// Whether the user can be prompted (e.g. for a new session when it expires)
+    pub interactive: bool,
+    /// Session store to take a session from when one turns out to be needed
+    pub store: StoreOptions,
 }
 
 impl Config {
@@ -44,9 +59,13 @@ impl Config {
         // Resolve thread count
         let thread_count = args.threads.unwrap_or_else(num_cpus);
 
+        let client_options = ClientOptions::from_args(&args);
+        let store = StoreOptions::from_args(&args);
+
         // Resolve session and user ID
         let user_id_provided = args.user_id.is_some();
-        let (session, user_id) = resolve_session_and_user_id(args.user_id, args.submit)?;
+        let (session, user_id) =
+            resolve_session_and_user_id(&client_options, &store, args.user_id, args.submit)?;
 
         Ok(Config {
             year_filter: args.year,
@@ -62,8 +81,188 @@ impl Config {
             session,
             auto_retry: args.auto_retry,
             quiet: args.quiet,
+            client_options,
+            interactive: store.interactive,
+            store,
         })
     }
+
+    /// Create an HTTP client identified by the configured contact
+    pub fn client(&self) -> Result<AocClient, CliError> {
+        self.client_options.build()
+    }
+
+    /// Take the session and user ID of the saved profile, for work that needs one
+    ///
+    /// Returns `None` when no profile can be used, so the caller can prompt instead.
+    pub fn stored_session(&self) -> Result<Option<(Zeroizing<String>, u64)>, CliError> {
+        let Some(profile) = self.store.load_profile()? else {
+            return Ok(None);
+        };
+        if self.user_id_provided && profile.user_id != self.user_id {
+            return Err(CliError::UserIdMismatch {
+                expected: self.user_id,
+                actual: profile.user_id,
+            });
+        }
+        Ok(Some((profile.session, profile.user_id)))
+    }
+}
+
+/// How HTTP clients identify themselves and which server they talk to
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// Contact sent in the User-Agent header
+    pub contact: Option<String>,
+    /// Server to use instead of adventofcode.com
+    pub base_url: Option<String>,
+}
+
+impl ClientOptions {
+    /// Resolve the contact (CLI, then `AOC_CONTACT`) and the base URL (CLI, then
+    /// `AOC_BASE_URL`)
+    pub fn from_args(args: &Args) -> Self {
+        Self {
+            contact: args
+                .contact
+                .clone()
+                .or_else(|| std::env::var("AOC_CONTACT").ok())
+                .filter(|c| !c.trim().is_empty()),
+            base_url: args
+                .base_url
+                .clone()
+                .or_else(|| std::env::var("AOC_BASE_URL").ok()),
+        }
+    }
+
+    /// Create an HTTP client
+    ///
+    /// Requests to adventofcode.com are paced, including when it is given as the
+    /// base URL; requests to any other server are not. Fails if no contact was configured, since AoC asks every tool to identify
+    /// who runs it.
+    pub fn build(&self) -> Result<AocClient, CliError> {
+        let contact = self.contact.as_deref().ok_or_else(|| {
+            CliError::Config(
+                "No contact for the User-Agent header; pass --contact or set AOC_CONTACT \
+                 (e.g. your email or repository URL)"
+                    .to_string(),
+            )
+        })?;
+        let mut builder = AocClient::builder(contact);
+        if let Some(url) = &self.base_url {
+            builder = builder.base_url(url)?;
+        }
+        if self.base_url.as_deref().is_none_or(is_aoc_url) {
+            builder = builder.min_request_interval(REQUEST_INTERVAL);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Whether `url` points at adventofcode.com
+fn is_aoc_url(url: &str) -> bool {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = rest
+        .split(['/', ':', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    host == "adventofcode.com" || host.ends_with(".adventofcode.com")
+}
+
+/// Location of the session store, the selected profile and how to unlock it
+pub struct StoreOptions {
+    /// Encrypted store file: `{cache_dir}/sessions.enc`
+    pub path: PathBuf,
+    /// Profile to read or write
+    pub profile: String,
+    /// Whether the profile was chosen with `--profile` or `AOC_PROFILE`
+    pub profile_explicit: bool,
+    /// Key file used instead of a passphrase
+    pub key_file: Option<PathBuf>,
+    /// Whether a passphrase may be prompted for
+    pub interactive: bool,
+}
+
+impl StoreOptions {
+    /// Resolve store options from CLI args and `AOC_PROFILE` / `AOC_KEY_FILE`
+    pub fn from_args(args: &Args) -> Self {
+        let profile = args
+            .profile
+            .clone()
+            .or_else(|| std::env::var("AOC_PROFILE").ok());
+        Self {
+            path: expand_tilde(&args.cache_dir).join(STORE_FILE),
+            profile_explicit: profile.is_some(),
+            profile: profile.unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+            key_file: args
+                .key_file
+                .clone()
+                .or_else(|| std::env::var_os("AOC_KEY_FILE").map(PathBuf::from))
+                .map(|path| expand_tilde(&path)),
+            interactive: !args.non_interactive && std::io::stdin().is_terminal(),
+        }
+    }
+
+    /// Get the store key: the key file, then `AOC_STORE_PASSPHRASE`, then a prompt
+    ///
+    /// With `create`, a missing key file is generated and a passphrase for a new
+    /// store is asked for twice.
+    pub fn key(&self, create: bool) -> Result<StoreKey, CliError> {
+        if let Some(path) = &self.key_file {
+            if create && create_key_file(path)? {
+                println!("Created key file {}", path.display());
+            }
+            return Ok(StoreKey::KeyFile(path.clone()));
+        }
+        if let Ok(passphrase) = std::env::var("AOC_STORE_PASSPHRASE") {
+            return Ok(StoreKey::Passphrase(Zeroizing::new(passphrase)));
+        }
+        if !self.interactive {
+            return Err(CliError::Config(
+                "Session store is locked: set AOC_STORE_PASSPHRASE or --key-file".to_string(),
+            ));
+        }
+
+        let read = |prompt: &str| {
+            rpassword::prompt_password(prompt)
+                .map(Zeroizing::new)
+                .map_err(|e| CliError::Config(format!("Failed to read passphrase: {}", e)))
+        };
+        let passphrase = read("Session store passphrase: ")?;
+        if create && !self.path.exists() && *read("Repeat passphrase: ")? != *passphrase {
+            return Err(CliError::Config("Passphrases do not match".to_string()));
+        }
+        Ok(StoreKey::Passphrase(passphrase))
+    }
+
+    /// Unlock the store and take the selected profile
+    ///
+    /// A missing store or profile gives `None`, as does a store that could only be
+    /// unlocked by prompting when prompts are disabled. Both are errors when the
+    /// profile was chosen explicitly.
+    pub fn load_profile(&self) -> Result<Option<Profile>, CliError> {
+        let locked = self.key_file.is_none()
+            && std::env::var_os("AOC_STORE_PASSPHRASE").is_none()
+            && !self.interactive;
+        let profile = if !self.path.exists() {
+            None
+        } else if locked && !self.profile_explicit {
+            eprintln!(
+                "Note: session store is locked (set AOC_STORE_PASSPHRASE or --key-file); not using it"
+            );
+            None
+        } else {
+            SessionStore::load(&self.path, &self.key(false)?)?.remove(&self.profile)
+        };
+        if profile.is_none() && self.profile_explicit {
+            return Err(CliError::Config(format!(
+                "No session is saved as profile '{}'; run `aoc login --profile {}` first",
+                self.profile, self.profile
+            )));
+        }
+        Ok(profile)
+    }
 }
 
 /// Expand ~ to home directory
@@ -114,8 +313,12 @@ pub fn prompt_session(reason: &str) -> Result<Zeroizing<String>, CliError> {
 }
 
 /// Verify session and optionally check user ID match
-pub fn verify_session(session: &str, expected_user_id: Option<u64>) -> Result<u64, CliError> {
-    let client = aoc_http_client::AocClient::new()?;
+pub fn verify_session(
+    client_options: &ClientOptions,
+    session: &str,
+    expected_user_id: Option<u64>,
+) -> Result<u64, CliError> {
+    let client = client_options.build()?;
     let info = client.verify_session(session)?;
     let actual_uid = info
         .user_id
@@ -133,12 +336,34 @@ pub fn verify_session(session: &str, expected_user_id: Option<u64>) -> Result<u6
 }
 
 /// Resolve session key and user ID
+///
+/// `AOC_SESSION` takes precedence, then the profile saved by `aoc login`; only
+/// then are the user ID and (when submitting) the session prompted for. The store
+/// is only opened when submitting or when no user ID was given; otherwise it is
+/// left for [`Config::stored_session`] once a session is actually needed.
 fn resolve_session_and_user_id(
+    client_options: &ClientOptions,
+    store: &StoreOptions,
     provided_user_id: Option<u64>,
     submit: bool,
 ) -> Result<(Zeroizing<String>, u64), CliError> {
     let env_session = std::env::var("AOC_SESSION").ok();
 
+    if env_session.is_none()
+        && (submit || provided_user_id.is_none())
+        && let Some(profile) = store.load_profile()?
+    {
+        if let Some(expected) = provided_user_id
+            && expected != profile.user_id
+        {
+            return Err(CliError::UserIdMismatch {
+                expected,
+                actual: profile.user_id,
+            });
+        }
+        return Ok((profile.session, profile.user_id));
+    }
+
     // Determine user ID: from CLI, from env session, or prompt
     // Track if user explicitly provided/entered a user ID (vs derived from session)
     let (user_id, user_provided_or_prompted) = match (provided_user_id, &env_session) {
@@ -161,7 +386,7 @@ fn resolve_session_and_user_id(
         } else {
             None
         };
-        verify_session(&session, expected)?
+        verify_session(client_options, &session, expected)?
     } else {
         // No session - user_id must have been provided or prompted
         user_id.expect("User ID should be set when no session is available")
@@ -169,3 +394,18 @@ fn resolve_session_and_user_id(
 
     Ok((session, user_id))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_aoc_url() {
+        assert!(is_aoc_url("https://adventofcode.com"));
+        assert!(is_aoc_url("https://AdventOfCode.com/"));
+        assert!(is_aoc_url("https://www.adventofcode.com:443/2023"));
+        assert!(!is_aoc_url("http://127.0.0.1:3000"));
+        assert!(!is_aoc_url("http://adventofcode.com.example.org"));
+        assert!(!is_aoc_url("http://localhost/adventofcode.com"));
+    }
+}
@@ -14,6 +14,10 @@ pub enum CliError {
     #[error("Cache error: {0}")]
     Cache(#[from] CacheError),
 
+    /// Session store error
+    #[error("Session store error: {0}")]
+    Store(#[from] StoreError),
+
     /// HTTP client error
     #[error("HTTP client error: {0}")]
     Http(#[from] aoc_http_client::AocError),
@@ -26,6 +30,16 @@ pub enum CliError {
     #[error("Registration error: {0}")]
     Registration(#[from] aoc_solver::RegistrationError),
 
+    /// The session was rejected mid-run and no new one could be prompted for
+    #[error(
+        "Session expired or was rejected by adventofcode.com. Set AOC_SESSION to a fresh session cookie and run again."
+    )]
+    SessionExpired,
+
+    /// A solver could not be generated
+    #[error("Scaffolding error: {0}")]
+    Scaffold(String),
+
     /// User ID mismatch
     #[error("User ID mismatch: expected {expected}, got {actual}")]
     UserIdMismatch { expected: u64, actual: u64 },
@@ -52,6 +66,10 @@ pub enum ExecutorError {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 
+    /// The session was rejected; the work is deferred until it is replaced
+    #[error("Session expired or was rejected by adventofcode.com")]
+    SessionExpired,
+
     /// Solver execution failed
     #[error("Solver execution failed: {0}")]
     Solver(#[from] aoc_solver::SolverError),
@@ -127,4 +145,26 @@ pub enum CacheError {
     /// Cache directory creation failed
     #[error("Cache directory creation failed: {0}")]
     DirCreation(String),
+
+    /// Content is an AoC error page rather than puzzle input
+    #[error("Refusing to cache input: {0}")]
+    InvalidInput(aoc_http_client::AocError),
+}
+
+/// Session store errors
+#[derive(Error, Debug)]
+pub enum StoreError {
+    /// IO error
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The key is wrong or the store was modified
+    #[error(
+        "Cannot unlock the session store: wrong passphrase or key file, or the file was modified"
+    )]
+    Locked,
+
+    /// The store or a profile is malformed
+    #[error("{0}")]
+    Format(String),
 }
@@ -1,15 +1,20 @@
 //! Parallel executor for running solvers
 
-use crate::cache::InputCache;
+use crate::answers::{AnswerStore, Verification};
+use crate::cache::{InputCache, InputData};
 use crate::cli::ParallelizeBy;
-use crate::config::Config;
+use crate::config::{ClientOptions, Config};
 use crate::error::{ArcExecutorError, ExecutorError};
-use aoc_http_client::AocClient;
-use aoc_solver::{DynSolver, SolverRegistry};
+use crate::history::{LoggedOutcome, Refusal, Submission, SubmissionLog};
+use aoc_http_client::{AnswerHint, AocApi, AocClient, YearProgress};
+use aoc_solver::{AnswerValidationError, ArenaStats, DynSolver, SolverRegistry};
 use chrono::{DateTime, Local, TimeDelta};
 use itertools::Itertools;
 use rayon::prelude::*;
+use std::collections::HashMap;
 use std::ops::RangeInclusive;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
 use zeroize::Zeroizing;
 
@@ -17,9 +22,23 @@ use zeroize::Zeroizing;
 #[derive(Debug, Clone)]
 pub enum SubmissionOutcome {
     Correct,
-    Incorrect,
+    Incorrect {
+        hint: Option<AnswerHint>,
+    },
     AlreadyCompleted,
-    Throttled { wait_time: Option<TimeDelta> },
+    /// The part is not the one currently being solved on AoC
+    WrongLevel,
+    /// Not submitted because the calendar shows the part already starred
+    AlreadyStarred,
+    /// Not submitted because the submission log rules the answer out
+    Refused(Refusal),
+    /// Not submitted because the session was rejected and not replaced
+    SessionExpired,
+    Throttled {
+        wait_time: Option<TimeDelta>,
+    },
+    /// AoC's response was not recognised
+    Unknown(String),
     Error(String),
 }
 
@@ -30,7 +49,12 @@ pub struct SolverResult {
     pub part: u8,
     pub answer: Result<String, aoc_solver::SolverError>,
     pub solve_duration: TimeDelta,
+    pub solve_cpu_duration: Option<TimeDelta>,
     pub parse_duration: Option<TimeDelta>,
+    pub parse_cpu_duration: Option<TimeDelta>,
+    pub parse_arena: Option<ArenaStats>,
+    pub validation_error: Option<AnswerValidationError>,
+    pub verification: Option<Verification>,
     pub submitted_at: Option<DateTime<Local>>,
     pub submission: Option<SubmissionOutcome>,
     pub submission_wait: Option<TimeDelta>,
@@ -43,17 +67,68 @@ pub struct WorkItem {
     pub parts: RangeInclusive<u8>,
 }
 
+/// Work set aside because the session was rejected mid-run
+#[derive(Default)]
+pub struct Deferred {
+    /// Work items whose input could not be fetched
+    work: Vec<WorkItem>,
+    /// Solved parts whose answers could not be submitted
+    submissions: Vec<SolverResult>,
+}
+
+impl Deferred {
+    /// Number of deferred work items and submissions
+    pub fn len(&self) -> usize {
+        self.work.len() + self.submissions.len()
+    }
+
+    /// Whether nothing was deferred
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Give up on the deferred work, returning a result for everything it covers
+    ///
+    /// Used when the session cannot be replaced. Solved parts come back marked
+    /// [`SubmissionOutcome::SessionExpired`]; parts whose input could not be
+    /// fetched come back as errors.
+    pub fn abandon(self) -> Vec<SolverResult> {
+        let mut results = self.submissions;
+        for result in &mut results {
+            result.submission = Some(SubmissionOutcome::SessionExpired);
+        }
+        let error = ExecutorError::SessionExpired.to_string();
+        for work in self.work {
+            for part in work.parts {
+                results.push(make_error_result(work.year, work.day, part, &error));
+            }
+        }
+        results
+    }
+}
+
 /// Parallel executor for running solvers
-pub struct Executor {
-    sync_executor_config: SyncExecutorConfig,
+///
+/// Generic over the [`AocApi`] used to fetch inputs and submit answers, so tests can
+/// run it against an in-memory fake instead of AoC.
+pub struct Executor<A: AocApi = AocClient> {
+    sync_executor_config: SyncExecutorConfig<A>,
     thread_pool: rayon::ThreadPool,
+    client_options: ClientOptions,
+    user_id: u64,
 }
 
-pub struct SyncExecutorConfig {
+pub struct SyncExecutorConfig<A: AocApi = AocClient> {
     registry: SolverRegistry,
     cache: InputCache,
-    client: Option<AocClient>,
+    answers: AnswerStore,
+    submissions: Mutex<SubmissionLog>,
+    progress: HashMap<u16, YearProgress>,
+    client: Option<A>,
     session: Zeroizing<String>,
+    /// Set when AoC rejects the session; later network work is deferred, not attempted
+    session_expired: AtomicBool,
+    deferred: Mutex<Deferred>,
     submit: bool,
     auto_retry: bool,
     parallelize_by: ParallelizeBy,
@@ -62,11 +137,11 @@ pub struct SyncExecutorConfig {
     part_filter: Option<u8>,
 }
 
-impl Executor {
+impl Executor<AocClient> {
     /// Create a new executor from config
     pub fn new(registry: SolverRegistry, config: &Config) -> Result<Self, ExecutorError> {
         let client = if config.submit || !config.session.is_empty() {
-            Some(AocClient::new().map_err(|e| ExecutorError::InputFetch {
+            Some(config.client().map_err(|e| ExecutorError::InputFetch {
                 year: 0,
                 day: 0,
                 source: Box::new(e),
@@ -74,18 +149,62 @@ impl Executor {
         } else {
             None
         };
+        Self::with_api(registry, config, client)
+    }
+
+    /// Update session and user ID (for late session acquisition)
+    pub fn update_session(
+        &mut self,
+        session: Zeroizing<String>,
+        user_id: u64,
+    ) -> Result<(), ExecutorError> {
+        // Create HTTP client if we don't have one yet
+        if self.sync_executor_config.client.is_none() {
+            self.sync_executor_config.client =
+                Some(
+                    self.client_options
+                        .build()
+                        .map_err(|e| ExecutorError::InputFetch {
+                            year: 0,
+                            day: 0,
+                            source: Box::new(e),
+                        })?,
+                );
+        }
+        self.set_session(session, user_id);
+        Ok(())
+    }
+}
 
+impl<A: AocApi> Executor<A> {
+    /// Create an executor from config that talks to AoC through `api`
+    ///
+    /// Without an API, inputs must already be cached and nothing can be submitted.
+    pub fn with_api(
+        registry: SolverRegistry,
+        config: &Config,
+        api: Option<A>,
+    ) -> Result<Self, ExecutorError> {
         let thread_pool = rayon::ThreadPoolBuilder::new()
             .num_threads(config.thread_count)
             .build()
             .map_err(|e| ExecutorError::ThreadPool(e.to_string()))?;
 
+        let cache = InputCache::new(config.cache_dir.as_path().into(), config.user_id);
+        let answers = load_answers(&cache);
+        let submissions = Mutex::new(load_submissions(&cache));
+
         Ok(Self {
             sync_executor_config: SyncExecutorConfig {
                 registry,
-                cache: InputCache::new(config.cache_dir.as_path().into(), config.user_id),
-                client,
+                cache,
+                answers,
+                submissions,
+                progress: HashMap::new(),
+                client: api,
                 session: config.session.clone(),
+                session_expired: AtomicBool::new(false),
+                deferred: Mutex::default(),
                 submit: config.submit,
                 auto_retry: config.auto_retry,
                 parallelize_by: config.parallelize_by,
@@ -94,6 +213,8 @@ impl Executor {
                 part_filter: config.part_filter,
             },
             thread_pool,
+            client_options: config.client_options.clone(),
+            user_id: config.user_id,
         })
     }
 
@@ -124,29 +245,120 @@ impl Executor {
         }
     }
 
-    /// Update session and user ID (for late session acquisition)
-    pub fn update_session(
-        &mut self,
-        session: Zeroizing<String>,
-        user_id: u64,
-    ) -> Result<(), ExecutorError> {
-        // Create HTTP client if we don't have one yet
-        if self.sync_executor_config.client.is_none() {
-            self.sync_executor_config.client =
-                Some(AocClient::new().map_err(|e| ExecutorError::InputFetch {
-                    year: 0,
-                    day: 0,
-                    source: Box::new(e),
-                })?);
-        }
-
-        // Update session
+    /// Replace the session and user ID, keeping the current API
+    pub fn set_session(&mut self, session: Zeroizing<String>, user_id: u64) {
+        // Update session, resuming network work deferred by an expired one
         self.sync_executor_config.session = session;
+        self.sync_executor_config
+            .session_expired
+            .store(false, Ordering::Relaxed);
 
-        // Update cache user_id
+        // Update cache user_id and reload that user's confirmed answers and submissions
+        self.user_id = user_id;
         self.sync_executor_config.cache.set_user_id(user_id);
+        self.sync_executor_config.answers = load_answers(&self.sync_executor_config.cache);
+        self.sync_executor_config.submissions =
+            Mutex::new(load_submissions(&self.sync_executor_config.cache));
+    }
 
-        Ok(())
+    /// Fetch calendar progress for every year with work, so starred parts are not submitted
+    ///
+    /// Does nothing unless submitting with a session. Years whose calendar cannot be
+    /// fetched are skipped with a warning and fall back to AoC's submission response.
+    pub fn load_progress(&mut self) {
+        let cfg = &self.sync_executor_config;
+        let Some(client) = cfg.client.as_ref() else {
+            return;
+        };
+        if !cfg.submit || cfg.session.is_empty() {
+            return;
+        }
+
+        let years: Vec<u16> = self
+            .collect_work_items()
+            .iter()
+            .map(|w| w.year)
+            .dedup()
+            .collect();
+        let mut progress = HashMap::new();
+        for year in years {
+            match client.get_calendar(year, &cfg.session) {
+                Ok(p) => {
+                    progress.insert(year, p);
+                }
+                Err(e) => eprintln!("Warning: failed to fetch {} calendar: {}", year, e),
+            }
+        }
+        self.sync_executor_config.progress = progress;
+    }
+
+    /// Get the input cache for the current user
+    pub fn cache(&self) -> &InputCache {
+        &self.sync_executor_config.cache
+    }
+
+    /// The user the cache and session belong to
+    pub fn user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    /// Take the work deferred because the session was rejected, if there is any
+    ///
+    /// Once AoC rejects the session, fetches and submissions are set aside instead
+    /// of failing one by one; solving from cached inputs carries on. After replacing
+    /// the session with [`set_session`](Self::set_session), pass the deferred
+    /// work to [`retry_deferred`](Self::retry_deferred).
+    pub fn take_deferred(&mut self) -> Option<Deferred> {
+        let deferred = std::mem::take(self.sync_executor_config.deferred.get_mut().unwrap());
+        (!deferred.is_empty()).then_some(deferred)
+    }
+
+    /// Run deferred work again with the current session, sending results to `tx`
+    pub fn retry_deferred(
+        &self,
+        deferred: Deferred,
+        tx: &Sender<SolverResult>,
+    ) -> Result<(), ArcExecutorError> {
+        let mut collected_error: Option<ArcExecutorError> = None;
+        for result in deferred.submissions {
+            if let Err(e) = send_result(tx, result, &self.sync_executor_config) {
+                collected_error = Some(ArcExecutorError::combine_opt(collected_error, e));
+            }
+        }
+        for work in deferred.work {
+            if let Err(e) = self.run_solver(&work, tx) {
+                collected_error = Some(ArcExecutorError::combine_opt(collected_error, e));
+            }
+        }
+        collected_error.map_or(Ok(()), Err)
+    }
+
+    /// Cache the input of a puzzle, fetching it unless it is already cached
+    ///
+    /// Returns whether the input was downloaded.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<bool, ExecutorError> {
+        let input = get_input_parallel(year, day, &self.sync_executor_config)?;
+        Ok(matches!(input, InputData::Owned(_)))
+    }
+
+    /// Verify and submit an answer given by hand, sending the result to `tx`
+    ///
+    /// The answer goes through the same checks as a solver's: answers of an
+    /// implausible shape are never sent, confirmed answers are not resubmitted,
+    /// answers ruled out by earlier submissions are refused, and a submission
+    /// rejected for an expired session is deferred.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: String,
+        tx: &Sender<SolverResult>,
+    ) -> Result<(), ArcExecutorError> {
+        let validation_error = aoc_solver::check_answer_shape(&answer).err();
+        let mut result = new_result(year, day, part, Ok(answer));
+        result.validation_error = validation_error;
+        send_result(tx, result, &self.sync_executor_config)
     }
 
     /// Execute all work items and send results to channel
@@ -240,47 +452,187 @@ impl Executor {
 
 /// Create an error result for a failed input fetch
 fn make_error_result(year: u16, day: u8, part: u8, error: &str) -> SolverResult {
-    SolverResult {
+    new_result(
         year,
         day,
         part,
-        answer: Err(aoc_solver::SolverError::ParseError(
+        Err(aoc_solver::SolverError::ParseError(
             aoc_solver::ParseError::InvalidFormat(error.to_string()),
         )),
+    )
+}
+
+/// Copy an error from creating a solver, to report it for each of its parts
+///
+/// `SolverError` is not `Clone`, as solve errors may hold any error; creating a
+/// solver only fails with the other variants.
+fn copy_creation_error(error: &aoc_solver::SolverError) -> aoc_solver::SolverError {
+    use aoc_solver::SolverError;
+    match error {
+        SolverError::NotFound(year, day) => SolverError::NotFound(*year, *day),
+        SolverError::InvalidYearDay(year, day) => SolverError::InvalidYearDay(*year, *day),
+        SolverError::OwnedNotSupported(year, day) => SolverError::OwnedNotSupported(*year, *day),
+        SolverError::ParseError(e) => SolverError::ParseError(e.clone()),
+        SolverError::SolveError(e) => {
+            SolverError::ParseError(aoc_solver::ParseError::Other(e.to_string()))
+        }
+    }
+}
+
+/// Create a result for an answer that was not computed by a solver
+fn new_result(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Result<String, aoc_solver::SolverError>,
+) -> SolverResult {
+    SolverResult {
+        year,
+        day,
+        part,
+        answer,
         solve_duration: TimeDelta::zero(),
+        solve_cpu_duration: None,
         parse_duration: None,
+        parse_cpu_duration: None,
+        parse_arena: None,
+        validation_error: None,
+        verification: None,
         submitted_at: None,
         submission: None,
         submission_wait: None,
     }
 }
 
-/// Send result with optional submission
-fn send_result(
+/// Load confirmed answers, warning and continuing without them on failure
+fn load_answers(cache: &InputCache) -> AnswerStore {
+    cache.get_answers().unwrap_or_else(|e| {
+        eprintln!("Warning: failed to load confirmed answers: {}", e);
+        AnswerStore::default()
+    })
+}
+
+/// Load the submission log, warning and continuing without it on failure
+fn load_submissions(cache: &InputCache) -> SubmissionLog {
+    cache.get_submissions().unwrap_or_else(|e| {
+        eprintln!("Warning: failed to load submission log: {}", e);
+        SubmissionLog::default()
+    })
+}
+
+/// Append a result's submission to the log and save it, if AoC responded
+fn record_submission<A: AocApi>(
+    result: &SolverResult,
+    sync_executor_config: &SyncExecutorConfig<A>,
+) {
+    let (Ok(answer), Some(outcome), Some(submitted_at)) =
+        (&result.answer, &result.submission, result.submitted_at)
+    else {
+        return;
+    };
+    let (outcome, hint) = match outcome {
+        SubmissionOutcome::Correct => (LoggedOutcome::Correct, None),
+        SubmissionOutcome::Incorrect { hint } => (LoggedOutcome::Incorrect, *hint),
+        SubmissionOutcome::AlreadyCompleted => (LoggedOutcome::AlreadyCompleted, None),
+        SubmissionOutcome::WrongLevel => (LoggedOutcome::WrongLevel, None),
+        SubmissionOutcome::Throttled { .. } => (LoggedOutcome::Throttled, None),
+        SubmissionOutcome::Unknown(_) => (LoggedOutcome::Unknown, None),
+        SubmissionOutcome::AlreadyStarred
+        | SubmissionOutcome::Refused(_)
+        | SubmissionOutcome::SessionExpired
+        | SubmissionOutcome::Error(_) => return,
+    };
+
+    let mut log = sync_executor_config.submissions.lock().unwrap();
+    log.push(Submission {
+        year: result.year,
+        day: result.day,
+        part: result.part,
+        answer: answer.clone(),
+        outcome,
+        hint,
+        submitted_at,
+    });
+    if let Err(e) = sync_executor_config.cache.put_submissions(&log) {
+        eprintln!("Warning: failed to save submission log: {}", e);
+    }
+}
+
+/// Send result after verifying it and optionally submitting it
+fn send_result<A: AocApi>(
     tx: &Sender<SolverResult>,
     mut result: SolverResult,
-    client: Option<&AocClient>,
-    session: &str,
-    submit: bool,
-    auto_retry: bool,
+    sync_executor_config: &SyncExecutorConfig<A>,
 ) -> Result<(), ArcExecutorError> {
-    if submit {
-        submit_result_internal(&mut result, client, session, auto_retry);
+    if let Ok(answer) = &result.answer {
+        result.verification =
+            sync_executor_config
+                .answers
+                .verify(result.year, result.day, result.part, answer);
+    }
+    let starred = sync_executor_config
+        .progress
+        .get(&result.year)
+        .is_some_and(|p| p.is_completed(result.day, result.part));
+    // Parts with a confirmed answer need no submission: the result is already known
+    if sync_executor_config.submit && result.verification.is_none() {
+        if starred {
+            result.submission = Some(SubmissionOutcome::AlreadyStarred);
+        } else {
+            let submitted = if sync_executor_config.session_expired.load(Ordering::Relaxed) {
+                Err(ExecutorError::SessionExpired)
+            } else {
+                submit_result_internal(
+                    &mut result,
+                    sync_executor_config.client.as_ref(),
+                    &sync_executor_config.session,
+                    sync_executor_config.auto_retry,
+                    &sync_executor_config.submissions,
+                )
+            };
+            // Hold the result back until the session is replaced
+            if submitted.is_err() {
+                sync_executor_config
+                    .session_expired
+                    .store(true, Ordering::Relaxed);
+                sync_executor_config
+                    .deferred
+                    .lock()
+                    .unwrap()
+                    .submissions
+                    .push(result);
+                return Ok(());
+            }
+            record_submission(&result, sync_executor_config);
+        }
     }
     tx.send(result)
         .map_err(|_| ExecutorError::ChannelSend.into())
 }
 
 /// Free function for parallel solver execution
-fn run_solver_parallel(
+fn run_solver_parallel<A: AocApi>(
     work: &WorkItem,
     tx: &Sender<SolverResult>,
-    sync_executor_config: &SyncExecutorConfig,
+    sync_executor_config: &SyncExecutorConfig<A>,
 ) -> Result<(), ArcExecutorError> {
     let parallelize_by = sync_executor_config.parallelize_by;
 
-    let input = match get_input_parallel(work, sync_executor_config) {
+    let input = match get_input_parallel(work.year, work.day, sync_executor_config) {
         Ok(input) => input,
+        Err(ExecutorError::SessionExpired) => {
+            sync_executor_config
+                .deferred
+                .lock()
+                .unwrap()
+                .work
+                .push(WorkItem {
+                    year: work.year,
+                    day: work.day,
+                    parts: work.parts.clone(),
+                });
+            return Ok(());
+        }
         Err(e) => {
             // Send error result for each part
             let error_msg = e.to_string();
@@ -300,28 +652,26 @@ fn run_solver_parallel(
 }
 
 /// Run solver with part-level parallelism, buffering results to emit in order
-fn run_solver_parts_parallel(
+fn run_solver_parts_parallel<A: AocApi>(
     work: &WorkItem,
-    input: &str,
+    input: &[u8],
     tx: &Sender<SolverResult>,
-    sync_executor_config: &SyncExecutorConfig,
+    sync_executor_config: &SyncExecutorConfig<A>,
 ) -> Result<(), ArcExecutorError> {
     let (result_tx, result_rx) = std::sync::mpsc::channel();
     let (year, day) = (work.year, work.day);
     let registry = &sync_executor_config.registry;
-    let session = &sync_executor_config.session;
-    let client = &sync_executor_config.client;
-    let submit = sync_executor_config.submit;
-    let auto_retry = sync_executor_config.auto_retry;
 
     // Solve parts in parallel
     work.parts
         .clone()
         .into_par_iter()
         .for_each_with(result_tx, |rtx, part| {
-            let mut solver = registry.create_solver(year, day, input).unwrap();
-            rtx.send(solve_part_internal(year, day, part, &mut *solver))
-                .ok();
+            let result = match registry.create_solver_from_bytes(year, day, input) {
+                Ok(mut solver) => solve_part_internal(year, day, part, &mut *solver),
+                Err(e) => new_result(year, day, part, Err(e)),
+            };
+            rtx.send(result).ok();
         });
 
     // Buffer and emit results in part order using a min-heap
@@ -335,7 +685,7 @@ fn run_solver_parts_parallel(
         // Emit buffered results in order
         while heap.peek().is_some_and(|r| r.0.part == next_part) {
             let result = heap.pop().unwrap().0;
-            send_result(tx, result, client.as_ref(), session, submit, auto_retry)?;
+            send_result(tx, result, sync_executor_config)?;
             next_part += 1;
         }
     }
@@ -364,23 +714,34 @@ impl PartialOrd for PartOrderedResult {
 }
 
 /// Run solver sequentially in background, submit as results arrive
-fn run_solver_sequential(
+fn run_solver_sequential<A: AocApi>(
     work: &WorkItem,
-    input: &str,
+    input: &[u8],
     tx: &Sender<SolverResult>,
-    sync_executor_config: &SyncExecutorConfig,
+    sync_executor_config: &SyncExecutorConfig<A>,
 ) -> Result<(), ArcExecutorError> {
     let (solve_tx, solve_rx) = std::sync::mpsc::channel();
     let (year, day) = (work.year, work.day);
     let parts = work.parts.clone();
     let registry = &sync_executor_config.registry;
-    let session = &sync_executor_config.session;
-    let client = &sync_executor_config.client;
-    let submit = sync_executor_config.submit;
-    let auto_retry = sync_executor_config.auto_retry;
     std::thread::scope(|s| {
         s.spawn(move || {
-            let mut solver = registry.create_solver(year, day, input).unwrap();
+            let mut solver = match registry.create_solver_from_bytes(year, day, input) {
+                Ok(solver) => solver,
+                Err(e) => {
+                    // Every part fails the same way when the input does not parse
+                    for part in parts {
+                        let error = copy_creation_error(&e);
+                        if solve_tx
+                            .send(new_result(year, day, part, Err(error)))
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    return;
+                }
+            };
             for part in parts {
                 if solve_tx
                     .send(solve_part_internal(year, day, part, &mut *solver))
@@ -392,31 +753,36 @@ fn run_solver_sequential(
         });
 
         for result in solve_rx {
-            send_result(tx, result, client.as_ref(), session, submit, auto_retry)?
+            send_result(tx, result, sync_executor_config)?
         }
         Ok(())
     })
 }
 
-/// Get input for a year/day, using cache or fetching (free function version)
-fn get_input_parallel(
-    work: &WorkItem,
-    sync_executor_config: &SyncExecutorConfig,
-) -> Result<String, ExecutorError> {
-    let (year, day) = (work.year, work.day);
+/// Get input for a year/day, memory-mapping the cache or fetching (free function version)
+fn get_input_parallel<A: AocApi>(
+    year: u16,
+    day: u8,
+    sync_executor_config: &SyncExecutorConfig<A>,
+) -> Result<InputData, ExecutorError> {
     let cache = &sync_executor_config.cache;
     let session = &sync_executor_config.session;
     let client = sync_executor_config.client.as_ref();
     // Check cache first
     if let Some(input) = cache
-        .get(year, day)
+        .get_mapped(year, day)
         .map_err(|e| ExecutorError::InputFetch {
             year,
             day,
             source: Box::new(e),
         })?
     {
-        return Ok(input);
+        return Ok(InputData::Mapped(input));
+    }
+
+    // Leave the fetch until the session is replaced once it is known to be rejected
+    if sync_executor_config.session_expired.load(Ordering::Relaxed) {
+        return Err(ExecutorError::SessionExpired);
     }
 
     // Fetch from AoC
@@ -426,13 +792,20 @@ fn get_input_parallel(
         source: Box::new(std::io::Error::other("No HTTP client available")),
     })?;
 
-    let input = client
-        .get_input(year, day, session)
-        .map_err(|e| ExecutorError::InputFetch {
-            year,
-            day,
-            source: Box::new(e),
-        })?;
+    let input = client.get_input(year, day, session).map_err(|e| {
+        if e.is_auth_failure() {
+            sync_executor_config
+                .session_expired
+                .store(true, Ordering::Relaxed);
+            ExecutorError::SessionExpired
+        } else {
+            ExecutorError::InputFetch {
+                year,
+                day,
+                source: Box::new(e),
+            }
+        }
+    })?;
 
     // Cache the input (warn on failure, don't fail the operation)
     if let Err(e) = cache.put(year, day, &input) {
@@ -446,18 +819,28 @@ fn get_input_parallel(
         );
     }
 
-    Ok(input)
+    Ok(InputData::Owned(input))
 }
 
 /// Solve a single part (free function)
 fn solve_part_internal(year: u16, day: u8, part: u8, solver: &mut dyn DynSolver) -> SolverResult {
     let answer = solver.solve(part);
     let parse_duration = Some(solver.parse_duration());
+    let parse_cpu_duration = solver.parse_cpu_duration();
+    let parse_arena = Some(solver.parse_arena_stats());
 
-    let (answer_str, solve_duration) = match answer {
-        Ok(result) => (Ok(result.answer.clone()), result.duration()),
-        Err(e) => (Err(e.into()), TimeDelta::zero()),
+    let (answer_str, solve_duration, solve_cpu_duration) = match answer {
+        Ok(result) => (
+            Ok(result.answer.clone()),
+            result.duration(),
+            result.cpu_duration(),
+        ),
+        Err(e) => (Err(e.into()), TimeDelta::zero(), None),
     };
+    let validation_error = answer_str
+        .as_ref()
+        .ok()
+        .and_then(|ans| solver.validate_answer(part, ans).err());
 
     SolverResult {
         year,
@@ -465,7 +848,12 @@ fn solve_part_internal(year: u16, day: u8, part: u8, solver: &mut dyn DynSolver)
         part,
         answer: answer_str,
         solve_duration,
+        solve_cpu_duration,
         parse_duration,
+        parse_cpu_duration,
+        parse_arena,
+        validation_error,
+        verification: None,
         submitted_at: None,
         submission: None,
         submission_wait: None,
@@ -473,13 +861,30 @@ fn solve_part_internal(year: u16, day: u8, part: u8, solver: &mut dyn DynSolver)
 }
 
 /// Submit a result (free function version)
-fn submit_result_internal(
+///
+/// Returns `ExecutorError::SessionExpired`, leaving the result untouched, if AoC
+/// rejected the session.
+fn submit_result_internal<A: AocApi>(
     result: &mut SolverResult,
-    client: Option<&AocClient>,
+    client: Option<&A>,
     session: &str,
     auto_retry: bool,
-) {
+    submissions: &Mutex<SubmissionLog>,
+) -> Result<(), ExecutorError> {
+    // Answers that failed validation are only reported, never sent
+    if result.validation_error.is_some() {
+        return Ok(());
+    }
     if let Ok(ref ans) = result.answer {
+        // Answers ruled out by earlier rejections would only earn a throttle
+        let refusal = submissions
+            .lock()
+            .unwrap()
+            .check(result.year, result.day, result.part, ans);
+        if let Some(refusal) = refusal {
+            result.submission = Some(SubmissionOutcome::Refused(refusal));
+            return Ok(());
+        }
         let (outcome, wait) = submit_with_retry_internal(
             result.year,
             result.day,
@@ -488,30 +893,31 @@ fn submit_result_internal(
             client,
             session,
             auto_retry,
-        );
+        )?;
         result.submitted_at = Some(Local::now());
         result.submission = outcome;
         result.submission_wait = wait;
     }
+    Ok(())
 }
 
 /// Submit answer with optional retry on throttle (free function version)
-fn submit_with_retry_internal(
+fn submit_with_retry_internal<A: AocApi>(
     year: u16,
     day: u8,
     part: u8,
     answer: &str,
-    client: Option<&AocClient>,
+    client: Option<&A>,
     session: &str,
     auto_retry: bool,
-) -> (Option<SubmissionOutcome>, Option<TimeDelta>) {
+) -> Result<(Option<SubmissionOutcome>, Option<TimeDelta>), ExecutorError> {
     let client = match client {
         Some(c) => c,
         None => {
-            return (
+            return Ok((
                 Some(SubmissionOutcome::Error("No HTTP client".into())),
                 None,
-            );
+            ));
         }
     };
 
@@ -520,13 +926,22 @@ fn submit_with_retry_internal(
     loop {
         match client.submit_answer(year, day, part, answer, session) {
             Ok(aoc_http_client::SubmissionResult::Correct) => {
-                return (Some(SubmissionOutcome::Correct), Some(total_wait));
+                return Ok((Some(SubmissionOutcome::Correct), Some(total_wait)));
             }
-            Ok(aoc_http_client::SubmissionResult::Incorrect) => {
-                return (Some(SubmissionOutcome::Incorrect), Some(total_wait));
+            Ok(aoc_http_client::SubmissionResult::Incorrect { hint }) => {
+                return Ok((
+                    Some(SubmissionOutcome::Incorrect { hint }),
+                    Some(total_wait),
+                ));
             }
             Ok(aoc_http_client::SubmissionResult::AlreadyCompleted) => {
-                return (Some(SubmissionOutcome::AlreadyCompleted), Some(total_wait));
+                return Ok((Some(SubmissionOutcome::AlreadyCompleted), Some(total_wait)));
+            }
+            Ok(aoc_http_client::SubmissionResult::WrongLevel) => {
+                return Ok((Some(SubmissionOutcome::WrongLevel), Some(total_wait)));
+            }
+            Ok(aoc_http_client::SubmissionResult::Unknown(text)) => {
+                return Ok((Some(SubmissionOutcome::Unknown(text)), Some(total_wait)));
             }
             Ok(aoc_http_client::SubmissionResult::Throttled { wait_time }) => {
                 if auto_retry && let Some(wait) = wait_time {
@@ -534,19 +949,412 @@ fn submit_with_retry_internal(
                     total_wait += TimeDelta::from_std(wait).unwrap_or(TimeDelta::zero());
                     continue;
                 }
-                return (
+                return Ok((
                     Some(SubmissionOutcome::Throttled {
                         wait_time: wait_time.and_then(|w| TimeDelta::from_std(w).ok()),
                     }),
                     Some(total_wait),
-                );
+                ));
             }
+            Err(e) if e.is_auth_failure() => return Err(ExecutorError::SessionExpired),
             Err(e) => {
-                return (
+                return Ok((
                     Some(SubmissionOutcome::Error(e.to_string())),
                     Some(total_wait),
-                );
+                ));
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StoreOptions;
+    use aoc_http_client::{AocError, FakeAocApi, FakeCall, FixtureMode, SubmissionResult};
+    use aoc_solver::{
+        AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, SolverRegistryBuilder,
+    };
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    #[derive(AocSolver)]
+    #[aoc_solver(max_parts = 2)]
+    struct Sum;
+
+    impl AocParser for Sum {
+        type SharedData<'a> = Vec<i64>;
+
+        fn parse<'a>(
+            input: &'a str,
+            _arena: &'a Arena,
+        ) -> Result<Self::SharedData<'a>, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| ParseError::InvalidFormat("Expected integer".into()))
+                })
+                .collect()
+        }
+    }
+
+    impl PartSolver<1> for Sum {
+        fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+            Ok(shared.iter().sum::<i64>().to_string())
+        }
+    }
+
+    impl PartSolver<2> for Sum {
+        fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+            Ok(shared.iter().product::<i64>().to_string())
+        }
+    }
+
+    fn registry() -> SolverRegistry {
+        let mut builder = SolverRegistryBuilder::new();
+        builder
+            .register(YEAR, DAY, 2, |input: &str| {
+                Ok(Box::new(aoc_solver::SolverInstance::<Sum>::new(
+                    YEAR, DAY, input,
+                )?))
+            })
+            .unwrap();
+        builder.build()
+    }
+
+    fn config(cache_dir: &TempDir, submit: bool, auto_retry: bool) -> Config {
+        Config {
+            year_filter: None,
+            day_filter: None,
+            part_filter: Some(1),
+            tags: Vec::new(),
+            cache_dir: cache_dir.path().to_path_buf(),
+            thread_count: 1,
+            parallelize_by: ParallelizeBy::Sequential,
+            submit,
+            user_id: 1,
+            user_id_provided: true,
+            session: Zeroizing::new("session".to_string()),
+            auto_retry,
+            quiet: true,
+            client_options: ClientOptions {
+                contact: Some("aoc-cli tests".to_string()),
+                base_url: None,
+            },
+            interactive: false,
+            store: StoreOptions {
+                path: cache_dir.path().join("sessions.enc"),
+                profile: "default".to_string(),
+                profile_explicit: false,
+                key_file: None,
+                interactive: false,
+            },
+        }
+    }
+
+    /// Run every work item and return the results in the order they were sent
+    fn run(executor: &Executor<FakeAocApi>) -> Vec<SolverResult> {
+        let (tx, rx) = mpsc::channel();
+        executor.execute(tx).unwrap();
+        rx.into_iter().collect()
+    }
+
+    fn api(executor: &Executor<FakeAocApi>) -> &FakeAocApi {
+        executor.sync_executor_config.client.as_ref().unwrap()
+    }
+
+    #[test]
+    fn test_fetch_failure_becomes_error_result() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new().with_input_error(YEAR, DAY, AocError::NotYetUnlocked);
+        let executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        let results = run(&executor);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].answer.is_err());
+        assert!(results[0].submission.is_none());
+        assert_eq!(
+            api(&executor).calls(),
+            vec![FakeCall::GetInput {
+                year: YEAR,
+                day: DAY
+            }]
+        );
+    }
+
+    #[test]
+    fn test_throttle_is_waited_out_with_auto_retry() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new()
+            .with_input(YEAR, DAY, "1\n2\n3")
+            .with_submission(
+                YEAR,
+                DAY,
+                1,
+                Ok(SubmissionResult::Throttled {
+                    wait_time: Some(Duration::from_millis(10)),
+                }),
+            )
+            .with_submission(YEAR, DAY, 1, Ok(SubmissionResult::Correct));
+        let executor =
+            Executor::with_api(registry(), &config(&temp, true, true), Some(fake)).unwrap();
+
+        let results = run(&executor);
+        assert_eq!(results[0].answer.as_deref().unwrap(), "6");
+        assert!(matches!(
+            results[0].submission,
+            Some(SubmissionOutcome::Correct)
+        ));
+        assert!(results[0].submission_wait.unwrap() >= TimeDelta::milliseconds(10));
+        let submits = api(&executor)
+            .calls()
+            .into_iter()
+            .filter(|c| matches!(c, FakeCall::SubmitAnswer { .. }))
+            .count();
+        assert_eq!(submits, 2);
+    }
+
+    #[test]
+    fn test_throttle_is_reported_without_auto_retry() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new()
+            .with_input(YEAR, DAY, "1\n2\n3")
+            .with_submission(
+                YEAR,
+                DAY,
+                1,
+                Ok(SubmissionResult::Throttled {
+                    wait_time: Some(Duration::from_secs(30)),
+                }),
+            );
+        let executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        let results = run(&executor);
+        assert!(matches!(
+            results[0].submission,
+            Some(SubmissionOutcome::Throttled { wait_time: Some(wait) }) if wait == TimeDelta::seconds(30)
+        ));
+    }
+
+    #[test]
+    fn test_submission_error_is_reported() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new()
+            .with_input(YEAR, DAY, "1\n2\n3")
+            .with_submission(YEAR, DAY, 1, Err(AocError::HtmlParse));
+        let executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        let results = run(&executor);
+        assert!(matches!(
+            &results[0].submission,
+            Some(SubmissionOutcome::Error(message)) if message.contains("HTML")
+        ));
+    }
+
+    #[test]
+    fn test_expired_session_defers_work_until_replaced() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new()
+            .with_input(YEAR, DAY, "1\n2\n3")
+            .with_input_error(YEAR, DAY, AocError::SessionExpired)
+            .with_submission(YEAR, DAY, 1, Ok(SubmissionResult::Correct));
+        let mut executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        assert!(run(&executor).is_empty());
+        let deferred = executor.take_deferred().unwrap();
+        assert_eq!(deferred.len(), 1);
+
+        executor.set_session(Zeroizing::new("fresh".to_string()), 1);
+        let (tx, rx) = mpsc::channel();
+        executor.retry_deferred(deferred, &tx).unwrap();
+        drop(tx);
+        let results: Vec<_> = rx.into_iter().collect();
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0].submission,
+            Some(SubmissionOutcome::Correct)
+        ));
+        assert!(executor.take_deferred().is_none());
+    }
+
+    #[test]
+    fn test_abandoned_work_is_reported_not_submitted() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new()
+            .with_input(YEAR, DAY, "1\n2\n3")
+            .with_submission(YEAR, DAY, 1, Err(AocError::SessionExpired));
+        let mut executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        assert!(run(&executor).is_empty());
+        let results = executor.take_deferred().unwrap().abandon();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].answer.as_deref().unwrap(), "6");
+        assert!(matches!(
+            results[0].submission,
+            Some(SubmissionOutcome::SessionExpired)
+        ));
+    }
+
+    #[test]
+    fn test_fetch_input_caches_once() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new().with_input(YEAR, DAY, "1\n2\n3");
+        let executor =
+            Executor::with_api(registry(), &config(&temp, false, false), Some(fake)).unwrap();
+
+        assert!(executor.fetch_input(YEAR, DAY).unwrap());
+        assert!(!executor.fetch_input(YEAR, DAY).unwrap());
+        assert!(executor.cache().contains(YEAR, DAY));
+        assert_eq!(api(&executor).calls().len(), 1);
+    }
+
+    #[test]
+    fn test_submit_answer_skips_confirmed_answers() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new().with_submission(YEAR, DAY, 1, Ok(SubmissionResult::Correct));
+        let mut executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        executor
+            .submit_answer(YEAR, DAY, 1, "6".to_string(), &tx)
+            .unwrap();
+        let result = rx.recv().unwrap();
+        assert!(matches!(
+            result.submission,
+            Some(SubmissionOutcome::Correct)
+        ));
+
+        // Once recorded as confirmed, the same answer is verified locally instead
+        let mut answers = executor.cache().get_answers().unwrap();
+        answers.insert(YEAR, DAY, 1, "6");
+        executor.cache().put_answers(&answers).unwrap();
+        executor.set_session(Zeroizing::new("session".to_string()), 1);
+        executor
+            .submit_answer(YEAR, DAY, 1, "6".to_string(), &tx)
+            .unwrap();
+        let result = rx.recv().unwrap();
+        assert_eq!(result.verification, Some(Verification::Verified));
+        assert!(result.submission.is_none());
+        assert_eq!(api(&executor).calls().len(), 1);
+    }
+
+    #[test]
+    fn test_unparsable_input_becomes_error_results() {
+        for parallelize_by in [ParallelizeBy::Sequential, ParallelizeBy::Part] {
+            let temp = TempDir::new().unwrap();
+            let mut config = config(&temp, true, false);
+            config.part_filter = None;
+            config.parallelize_by = parallelize_by;
+            let executor =
+                Executor::with_api(registry(), &config, Some(FakeAocApi::new())).unwrap();
+            // Not UTF-8, so the `&str` parser never sees it
+            std::fs::create_dir_all(executor.cache().cache_path(YEAR, DAY).parent().unwrap())
+                .unwrap();
+            std::fs::write(executor.cache().cache_path(YEAR, DAY), b"1\n\xff\n").unwrap();
+
+            let results = run(&executor);
+            assert_eq!(results.len(), 2);
+            for result in &results {
+                assert!(matches!(
+                    &result.answer,
+                    Err(aoc_solver::SolverError::ParseError(ParseError::InvalidFormat(m)))
+                        if m.contains("UTF-8")
+                ));
+                assert!(result.submission.is_none());
+            }
+            assert!(api(&executor).calls().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_submit_answer_never_sends_implausible_answers() {
+        let temp = TempDir::new().unwrap();
+        let fake = FakeAocApi::new().with_submission(YEAR, DAY, 1, Ok(SubmissionResult::Correct));
+        let executor =
+            Executor::with_api(registry(), &config(&temp, true, false), Some(fake)).unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        for answer in ["", "0", "-5", "1 2"] {
+            executor
+                .submit_answer(YEAR, DAY, 1, answer.to_string(), &tx)
+                .unwrap();
+            let result = rx.recv().unwrap();
+            assert!(result.validation_error.is_some(), "{:?}", answer);
+            assert!(result.submission.is_none());
+        }
+        assert!(api(&executor).calls().is_empty());
+    }
+
+    /// Day 1 of 2023, part 1: sum of the first and last digit of every line
+    #[derive(AocSolver)]
+    #[aoc_solver(max_parts = 1)]
+    struct Calibration;
+
+    impl AocParser for Calibration {
+        type SharedData<'a> = Vec<&'a str>;
+
+        fn parse<'a>(
+            input: &'a str,
+            _arena: &'a Arena,
+        ) -> Result<Self::SharedData<'a>, ParseError> {
+            Ok(input.lines().collect())
+        }
+    }
+
+    impl PartSolver<1> for Calibration {
+        fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+            let value = |line: &str| {
+                let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+                let first = digits.next().unwrap_or(0);
+                first * 10 + digits.next_back().unwrap_or(first)
+            };
+            Ok(shared.iter().map(|l| value(l)).sum::<u32>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_run_against_recorded_exchanges() {
+        let temp = TempDir::new().unwrap();
+        let mut builder = SolverRegistryBuilder::new();
+        builder
+            .register(YEAR, DAY, 1, |input: &str| {
+                Ok(Box::new(aoc_solver::SolverInstance::<Calibration>::new(
+                    YEAR, DAY, input,
+                )?))
+            })
+            .unwrap();
+        let fixtures = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../aoc-http-client/tests/fixtures/http"
+        );
+        let client = AocClient::builder("aoc-cli tests")
+            .fixtures(FixtureMode::Replay(fixtures.into()))
+            .build()
+            .unwrap();
+        let executor =
+            Executor::with_api(builder.build(), &config(&temp, true, false), Some(client)).unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        executor.execute(tx).unwrap();
+        let results: Vec<_> = rx.into_iter().collect();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].answer.as_deref().unwrap(), "142");
+        assert!(matches!(
+            results[0].submission,
+            Some(SubmissionOutcome::Correct)
+        ));
+        assert!(executor.cache().contains(YEAR, DAY));
+    }
+}
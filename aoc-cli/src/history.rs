@@ -0,0 +1,302 @@
+//! Local log of answers submitted to Advent of Code
+//!
+//! Stored per user as `submissions.tsv` in the cache directory, one
+//! `{year}_day{day:02}<TAB>{part}<TAB>{answer}<TAB>{outcome}<TAB>{hint}<TAB>{timestamp}`
+//! line per submission. The hint is `high`, `low` or empty and the timestamp is RFC 3339.
+
+use aoc_http_client::AnswerHint;
+use chrono::{DateTime, Local};
+use std::fmt;
+
+/// AoC's response to a logged submission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoggedOutcome {
+    Correct,
+    Incorrect,
+    AlreadyCompleted,
+    WrongLevel,
+    Throttled,
+    Unknown,
+}
+
+impl LoggedOutcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            LoggedOutcome::Correct => "correct",
+            LoggedOutcome::Incorrect => "incorrect",
+            LoggedOutcome::AlreadyCompleted => "already_completed",
+            LoggedOutcome::WrongLevel => "wrong_level",
+            LoggedOutcome::Throttled => "throttled",
+            LoggedOutcome::Unknown => "unknown",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "correct" => LoggedOutcome::Correct,
+            "incorrect" => LoggedOutcome::Incorrect,
+            "already_completed" => LoggedOutcome::AlreadyCompleted,
+            "wrong_level" => LoggedOutcome::WrongLevel,
+            "throttled" => LoggedOutcome::Throttled,
+            "unknown" => LoggedOutcome::Unknown,
+            _ => return None,
+        })
+    }
+}
+
+/// One answer sent to AoC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: LoggedOutcome,
+    pub hint: Option<AnswerHint>,
+    pub submitted_at: DateTime<Local>,
+}
+
+/// Reason an answer is not sent to AoC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The same answer was already rejected
+    KnownWrong,
+    /// The answer is not below an answer AoC called too high
+    TooHigh { bound: String },
+    /// The answer is not above an answer AoC called too low
+    TooLow { bound: String },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::KnownWrong => write!(f, "already rejected by AoC"),
+            Refusal::TooHigh { bound } => write!(f, "not below {}, which was too high", bound),
+            Refusal::TooLow { bound } => write!(f, "not above {}, which was too low", bound),
+        }
+    }
+}
+
+/// Every submission made, in the order they were made
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    entries: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Parse the TSV format, skipping blank, comment and malformed lines
+    pub fn parse(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let [key, part, answer, outcome, hint, timestamp] = fields[..] else {
+                    return None;
+                };
+                let (year, day) = key.split_once("_day")?;
+                let hint = match hint {
+                    "high" => Some(AnswerHint::TooHigh),
+                    "low" => Some(AnswerHint::TooLow),
+                    _ => None,
+                };
+                Some(Submission {
+                    year: year.parse().ok()?,
+                    day: day.parse().ok()?,
+                    part: part.parse().ok()?,
+                    answer: answer.to_string(),
+                    outcome: LoggedOutcome::parse(outcome)?,
+                    hint,
+                    submitted_at: DateTime::parse_from_rfc3339(timestamp.trim())
+                        .ok()?
+                        .with_timezone(&Local),
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Serialize to the TSV format
+    pub fn to_tsv(&self) -> String {
+        self.entries
+            .iter()
+            .map(|s| {
+                let hint = match s.hint {
+                    Some(AnswerHint::TooHigh) => "high",
+                    Some(AnswerHint::TooLow) => "low",
+                    None => "",
+                };
+                format!(
+                    "{}_day{:02}\t{}\t{}\t{}\t{}\t{}\n",
+                    s.year,
+                    s.day,
+                    s.part,
+                    s.answer,
+                    s.outcome.as_str(),
+                    hint,
+                    s.submitted_at.to_rfc3339()
+                )
+            })
+            .collect()
+    }
+
+    /// Append a submission
+    pub fn push(&mut self, submission: Submission) {
+        self.entries.push(submission);
+    }
+
+    /// Check whether an answer can be ruled out from earlier rejections
+    ///
+    /// Numeric answers are compared against the lowest answer AoC called too
+    /// high and the highest it called too low.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Refusal> {
+        let rejected: Vec<&Submission> = self
+            .entries
+            .iter()
+            .filter(|s| (s.year, s.day, s.part) == (year, day, part))
+            .filter(|s| s.outcome == LoggedOutcome::Incorrect)
+            .collect();
+        if rejected.iter().any(|s| s.answer == answer) {
+            return Some(Refusal::KnownWrong);
+        }
+
+        let value: i128 = answer.parse().ok()?;
+        let mut too_high: Option<(i128, &String)> = None;
+        let mut too_low: Option<(i128, &String)> = None;
+        for s in rejected {
+            let Ok(v) = s.answer.parse::<i128>() else {
+                continue;
+            };
+            match s.hint {
+                Some(AnswerHint::TooHigh) if too_high.is_none_or(|(h, _)| v < h) => {
+                    too_high = Some((v, &s.answer))
+                }
+                Some(AnswerHint::TooLow) if too_low.is_none_or(|(l, _)| v > l) => {
+                    too_low = Some((v, &s.answer))
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((high, bound)) = too_high
+            && value >= high
+        {
+            return Some(Refusal::TooHigh {
+                bound: bound.clone(),
+            });
+        }
+        if let Some((low, bound)) = too_low
+            && value <= low
+        {
+            return Some(Refusal::TooLow {
+                bound: bound.clone(),
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn submission(answer: &str, outcome: LoggedOutcome, hint: Option<AnswerHint>) -> Submission {
+        Submission {
+            year: 2023,
+            day: 5,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+            hint,
+            submitted_at: Local::now(),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut log = SubmissionLog::default();
+        log.push(submission("abc", LoggedOutcome::Incorrect, None));
+        log.push(submission(
+            "500",
+            LoggedOutcome::Incorrect,
+            Some(AnswerHint::TooHigh),
+        ));
+        log.push(submission(
+            "400",
+            LoggedOutcome::Incorrect,
+            Some(AnswerHint::TooHigh),
+        ));
+        log.push(submission(
+            "100",
+            LoggedOutcome::Incorrect,
+            Some(AnswerHint::TooLow),
+        ));
+        log.push(submission("300", LoggedOutcome::Throttled, None));
+
+        assert_eq!(log.check(2023, 5, 1, "abc"), Some(Refusal::KnownWrong));
+        assert_eq!(
+            log.check(2023, 5, 1, "450"),
+            Some(Refusal::TooHigh {
+                bound: "400".to_string()
+            })
+        );
+        assert_eq!(
+            log.check(2023, 5, 1, "99"),
+            Some(Refusal::TooLow {
+                bound: "100".to_string()
+            })
+        );
+        assert_eq!(log.check(2023, 5, 1, "300"), None);
+        assert_eq!(log.check(2023, 5, 1, "xyz"), None);
+        assert_eq!(log.check(2023, 5, 2, "abc"), None);
+    }
+
+    #[test]
+    fn test_parse_skips_malformed_lines() {
+        let log = SubmissionLog::parse(
+            "# comment\n\
+             2023_day05\t1\t35\tincorrect\tlow\t2023-12-05T06:00:00+01:00\n\
+             2023_day05\t1\t35\tbogus\t\t2023-12-05T06:00:00+01:00\n\
+             2023_day05\t1\t35\n",
+        );
+        assert_eq!(log.entries.len(), 1);
+        assert_eq!(log.entries[0].hint, Some(AnswerHint::TooLow));
+    }
+
+    // **Feature: submission-history, Property 1: TSV round trip**
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(50))]
+
+        #[test]
+        fn prop_tsv_roundtrip(
+            entries in prop::collection::vec(
+                (
+                    1u8..=25,
+                    1u8..=2,
+                    "[A-Za-z0-9,]{1,20}",
+                    prop::sample::select(vec![
+                        LoggedOutcome::Correct,
+                        LoggedOutcome::Incorrect,
+                        LoggedOutcome::WrongLevel,
+                        LoggedOutcome::Unknown,
+                    ]),
+                    prop::option::of(prop::sample::select(vec![
+                        AnswerHint::TooHigh,
+                        AnswerHint::TooLow,
+                    ])),
+                ),
+                0..20,
+            ),
+        ) {
+            let mut log = SubmissionLog::default();
+            for (day, part, answer, outcome, hint) in entries {
+                let submitted_at = DateTime::parse_from_rfc3339("2023-12-05T06:00:00+00:00")
+                    .unwrap()
+                    .with_timezone(&Local);
+                log.push(Submission { year: 2023, day, part, answer, outcome, hint, submitted_at });
+            }
+            prop_assert_eq!(SubmissionLog::parse(&log.to_tsv()), log);
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! AOC CLI - Command-line interface for running Advent of Code solvers
+//!
+//! The `aoc` binary runs this workspace's aoc-solutions. Any crate can get the
+//! same CLI (caching, execution, submission, output) over its own solvers: the
+//! inventory plugins linked into the binary are registered on top of the given
+//! [`SolverRegistryBuilder`], filtered by `--tags`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! // Link the crate whose `#[derive(AutoRegisterSolver)]` solvers to run
+//! // use my_solutions as _;
+//!
+//! aoc_cli::main!();
+//! ```
+//!
+//! Solvers registered by hand go in the builder and are not filtered by tags:
+//!
+//! ```no_run
+//! use aoc_cli::{Args, SolverRegistryBuilder};
+//! use clap::Parser;
+//!
+//! fn main() -> Result<(), aoc_cli::CliError> {
+//!     let builder = SolverRegistryBuilder::new();
+//!     // builder.register(2024, 1, 2, |input| ...)?;
+//!     aoc_cli::run(Args::parse(), builder)
+//! }
+//! ```
+
+mod aggregator;
+mod answers;
+mod cache;
+pub mod cli;
+mod commands;
+mod config;
+mod error;
+mod executor;
+mod history;
+mod output;
+mod scaffold;
+mod session_store;
+
+pub use aoc_solver::SolverRegistryBuilder;
+pub use cli::Args;
+pub use error::CliError;
+
+use aoc_http_client::SystemClock;
+use clap::Parser;
+use cli::{AnswersCommand, Command, DaySelector, PartSelector};
+use config::Config;
+use executor::Executor;
+use output::OutputFormatter;
+
+/// Define `fn main` running the CLI
+///
+/// `main!()` starts from an empty registry, so only inventory plugins are run;
+/// `main!(builder)` starts from the given [`SolverRegistryBuilder`].
+#[macro_export]
+macro_rules! main {
+    () => {
+        $crate::main!($crate::SolverRegistryBuilder::new());
+    };
+    ($builder:expr) => {
+        fn main() {
+            $crate::main_with($builder)
+        }
+    };
+}
+
+/// Parse the command line and run it, exiting with status 1 on error
+pub fn main_with(builder: SolverRegistryBuilder) {
+    let args = Args::parse();
+
+    if let Err(e) = run(args, builder) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Run a parsed command line over the solvers in `builder` and the linked plugins
+pub fn run(mut args: Args, builder: SolverRegistryBuilder) -> Result<(), CliError> {
+    let command = args.command.take();
+
+    // Session store commands must not resolve (and possibly prompt for) a session first
+    match command {
+        Some(Command::Login) => {
+            let store = config::StoreOptions::from_args(&args);
+            let client_options = config::ClientOptions::from_args(&args);
+            return commands::login(&store, &client_options, args.user_id);
+        }
+        Some(Command::Logout { all }) => {
+            return commands::logout(&config::StoreOptions::from_args(&args), all);
+        }
+        // A manual submission goes through the same checks as a solver's answer
+        Some(Command::Submit { .. }) => args.submit = true,
+        _ => {}
+    }
+
+    // Build config from args (may not have session yet)
+    let mut config = Config::from_args(args)?;
+
+    match command {
+        Some(Command::Read { puzzle, refresh }) => {
+            return commands::read(&config, puzzle, refresh);
+        }
+        Some(Command::Answers { action }) => {
+            return match action {
+                AnswersCommand::List => commands::answers_list(&config),
+                AnswersCommand::Set { puzzle, answer } => {
+                    commands::answers_set(&config, puzzle, &answer)
+                }
+                AnswersCommand::Remove { puzzle } => commands::answers_remove(&config, puzzle),
+                AnswersCommand::Sync { year, days } => commands::sync_answers(&config, year, days),
+            };
+        }
+        Some(Command::SyncAnswers { year, days }) => {
+            return commands::sync_answers(&config, year, days);
+        }
+        Some(Command::Leaderboard { year, id }) => {
+            return commands::leaderboard(&config, year, id, config.day_filter);
+        }
+        Some(Command::New {
+            puzzle,
+            solutions_dir,
+            template_dir,
+            fetch,
+            force,
+        }) => {
+            let options = commands::NewOptions {
+                solutions_dir,
+                template_dir,
+                fetch,
+                force,
+            };
+            return commands::new_day(&config, puzzle, &options);
+        }
+        Some(Command::Calendar { years }) => {
+            return commands::calendar(&config, &years);
+        }
+        Some(Command::Wait { puzzle, solve }) => {
+            commands::wait(&config, puzzle, &SystemClock)?;
+            if !solve {
+                return Ok(());
+            }
+            // Fall through to a normal run restricted to the unlocked day
+            config.year_filter = Some(puzzle.year);
+            config.day_filter = Some(puzzle.day);
+        }
+        _ => {}
+    }
+
+    // Build registry with tag filtering (only once); a manual submission needs no solvers
+    let registry = match command {
+        Some(Command::Submit { .. }) => SolverRegistryBuilder::new().build(),
+        _ => build_registry(builder, &config.tags)?,
+    };
+
+    if let Some(Command::Examples { puzzle, refresh }) = command {
+        return commands::examples(&config, &registry, puzzle, refresh);
+    }
+
+    // Create executor
+    let mut executor =
+        Executor::new(registry, &config).map_err(|e| error::CliError::Config(e.to_string()))?;
+
+    match command {
+        Some(Command::List) => return commands::list(&executor),
+        Some(Command::Submit { puzzle, answer }) => {
+            let PartSelector { year, day, part } = puzzle;
+            let expected_keys = vec![aggregator::ResultKey { year, day, part }];
+            return run_executor(executor, &config, expected_keys, move |executor, tx| {
+                executor.submit_answer(year, day, part, answer, &tx)
+            });
+        }
+        _ => {}
+    }
+
+    // Collect work items
+    let work_items = executor.collect_work_items();
+
+    if let Some(Command::Fetch { mut puzzles }) = command {
+        // Default to the days of the selected solvers
+        if puzzles.is_empty() {
+            puzzles = work_items
+                .iter()
+                .map(|w| DaySelector {
+                    year: w.year,
+                    day: w.day,
+                })
+                .collect();
+        }
+        let missing = check_missing_inputs(puzzles.iter().map(|p| (p.year, p.day)), &config);
+        if !missing.is_empty() {
+            ensure_session(&mut executor, &config)?;
+        }
+        return commands::fetch(&mut executor, &config, &puzzles);
+    }
+
+    if work_items.is_empty() {
+        println!("No solvers found matching the specified filters.");
+        return Ok(());
+    }
+
+    // Check for missing inputs early
+    let missing_inputs = check_missing_inputs(work_items.iter().map(|w| (w.year, w.day)), &config);
+    if !missing_inputs.is_empty() {
+        println!("Missing {} input file(s):", missing_inputs.len());
+        for (year, day) in &missing_inputs {
+            println!("  - {}/day{:02}", year, day);
+        }
+
+        if config.session.is_empty() {
+            println!();
+        } else {
+            println!("Will fetch missing inputs using provided session...");
+        }
+        ensure_session(&mut executor, &config)?;
+    }
+
+    executor.load_progress();
+    println!("Running {} solver(s)...", work_items.len());
+
+    // Build expected keys for result aggregation
+    let expected_keys: Vec<aggregator::ResultKey> = work_items
+        .iter()
+        .flat_map(|w| {
+            w.parts.clone().map(move |p| aggregator::ResultKey {
+                year: w.year,
+                day: w.day,
+                part: p,
+            })
+        })
+        .collect();
+
+    run_executor(executor, &config, expected_keys, |executor, tx| {
+        executor.execute(tx)
+    })
+}
+
+/// Check which inputs are missing from cache
+fn check_missing_inputs(days: impl Iterator<Item = (u16, u8)>, config: &Config) -> Vec<(u16, u8)> {
+    let cache = cache::InputCache::new(config.cache_dir.clone(), config.user_id);
+    days.filter(|&(year, day)| !cache.contains(year, day))
+        .collect()
+}
+
+/// Get a session to fetch missing inputs with, unless one is configured
+///
+/// Takes the saved profile if it can be used, and prompts otherwise.
+fn ensure_session(executor: &mut Executor, config: &Config) -> Result<(), error::CliError> {
+    if !config.session.is_empty() {
+        return Ok(());
+    }
+    if let Some((session, user_id)) = config.stored_session()? {
+        return executor
+            .update_session(session, user_id)
+            .map_err(|e| error::CliError::Config(e.to_string()));
+    }
+    let session = config::prompt_session(
+        "Session token required to fetch missing inputs from adventofcode.com",
+    )?;
+
+    // Verify and get user ID (check match if user_id was explicitly provided)
+    let expected = if config.user_id_provided {
+        Some(config.user_id)
+    } else {
+        None
+    };
+    let actual_user_id = config::verify_session(&config.client_options, &session, expected)?;
+
+    // Update executor with new session and user_id
+    executor
+        .update_session(session, actual_user_id)
+        .map_err(|e| error::CliError::Config(e.to_string()))
+}
+
+/// Run `work` on the executor in the background and print results as they arrive
+///
+/// `expected_keys` are the results `work` produces, in the order to print them.
+/// Work deferred by a rejected session is retried after prompting for a new one.
+/// The summary is printed and confirmed answers are recorded even if the run fails.
+fn run_executor(
+    executor: Executor,
+    config: &Config,
+    expected_keys: Vec<aggregator::ResultKey>,
+    work: impl FnOnce(
+        &Executor,
+        std::sync::mpsc::Sender<executor::SolverResult>,
+    ) -> Result<(), error::ArcExecutorError>
+    + Send
+    + 'static,
+) -> Result<(), error::CliError> {
+    // Keep the cache handle to record newly confirmed answers afterwards
+    let cache = executor.cache().clone();
+
+    // Collect and display results in order using aggregator
+    let formatter = OutputFormatter::new(config.quiet, config.submit);
+    let mut aggregator = aggregator::ResultAggregator::new(expected_keys);
+    let mut results = Vec::new();
+    let mut print = |result| {
+        // Add to aggregator and print any results that are ready (in order)
+        for ready in aggregator.add(result) {
+            formatter.print_result(&ready);
+            results.push(ready);
+        }
+    };
+
+    let (mut executor, mut outcome) = run_in_background(executor, work, &mut print)?;
+
+    // Prompt here rather than on the executor thread, so no result is printed
+    // over the prompt
+    while let Some(deferred) = executor.take_deferred() {
+        if outcome.is_ok() && config.interactive {
+            match reauthenticate(&mut executor, &config.client_options, deferred.len()) {
+                Ok(()) => {
+                    (executor, outcome) = run_in_background(
+                        executor,
+                        move |executor, tx| executor.retry_deferred(deferred, &tx),
+                        &mut print,
+                    )?;
+                    continue;
+                }
+                Err(e) => outcome = Err(e),
+            }
+        }
+        // Without a new session the deferred parts are reported as not done
+        deferred.abandon().into_iter().for_each(&mut print);
+        if outcome.is_ok() {
+            outcome = Err(error::CliError::SessionExpired);
+        }
+    }
+
+    // Drain any remaining buffered results (shouldn't happen if all results arrived)
+    for ready in aggregator.drain() {
+        formatter.print_result(&ready);
+        results.push(ready);
+    }
+
+    // Verify all expected results were received
+    if !aggregator.is_complete() {
+        eprintln!("Warning: Not all expected results were received");
+    }
+
+    // Print summary
+    formatter.print_summary(&results);
+
+    record_correct_answers(&cache, &results);
+
+    outcome
+}
+
+/// Run `work` on a background thread, passing every result it sends to `print`
+///
+/// Returns the executor together with the outcome of `work` once it finished.
+fn run_in_background(
+    executor: Executor,
+    work: impl FnOnce(
+        &Executor,
+        std::sync::mpsc::Sender<executor::SolverResult>,
+    ) -> Result<(), error::ArcExecutorError>
+    + Send
+    + 'static,
+    print: &mut impl FnMut(executor::SolverResult),
+) -> Result<(Executor, Result<(), error::CliError>), error::CliError> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let executor_handle = std::thread::spawn(move || {
+        let result = work(&executor, tx);
+        (executor, result)
+    });
+
+    for result in rx {
+        print(result);
+    }
+
+    // Wait for executor to finish
+    let (executor, result) = executor_handle
+        .join()
+        .map_err(|_| error::CliError::Config("Executor thread panicked".to_string()))?;
+    Ok((executor, result.map_err(error::CliError::from)))
+}
+
+/// Prompt for a session to replace the one AoC rejected
+///
+/// The new session must belong to the same user, since the cache and logs are
+/// per user.
+fn reauthenticate(
+    executor: &mut Executor,
+    client_options: &config::ClientOptions,
+    waiting: usize,
+) -> Result<(), error::CliError> {
+    let session = config::prompt_session(&format!(
+        "\nSession expired or was rejected; {} item(s) are waiting for a new one.",
+        waiting
+    ))?;
+    let user_id = config::verify_session(client_options, &session, Some(executor.user_id()))?;
+    executor
+        .update_session(session, user_id)
+        .map_err(|e| error::CliError::Config(e.to_string()))
+}
+
+/// Add answers accepted on submission to the confirmed answers store
+fn record_correct_answers(cache: &cache::InputCache, results: &[executor::SolverResult]) {
+    let correct: Vec<_> = results
+        .iter()
+        .filter(|r| matches!(r.submission, Some(executor::SubmissionOutcome::Correct)))
+        .filter_map(|r| Some((r.year, r.day, r.part, r.answer.as_ref().ok()?)))
+        .collect();
+    if correct.is_empty() {
+        return;
+    }
+
+    let result = cache.get_answers().and_then(|mut store| {
+        for (year, day, part, answer) in correct {
+            store.insert(year, day, part, answer.as_str());
+        }
+        cache.put_answers(&store)
+    });
+    if let Err(e) = result {
+        eprintln!("Warning: failed to record confirmed answers: {}", e);
+    }
+}
+
+/// Add the linked plugins to `builder`, keeping those with every tag in `tags`
+fn build_registry(
+    builder: SolverRegistryBuilder,
+    tags: &[String],
+) -> Result<aoc_solver::SolverRegistry, CliError> {
+    let builder = if tags.is_empty() {
+        builder.register_all_plugins()?
+    } else {
+        builder.register_solver_plugins(|plugin| {
+            tags.iter().all(|tag| plugin.tags.contains(&tag.as_str()))
+        })?
+    };
+
+    Ok(builder.build())
+}
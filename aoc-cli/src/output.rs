@@ -1,19 +1,28 @@
 //! Output formatting for solver results
 
+use crate::answers::Verification;
 use crate::executor::{SolverResult, SubmissionOutcome};
-use chrono::TimeDelta;
+use aoc_http_client::{AnswerHint, PrivateLeaderboard, YearProgress, unlock_time};
+use aoc_solver::ArenaStats;
+use chrono::{DateTime, TimeDelta, Utc};
+use std::time::Duration;
 
 /// Output formatter for solver results
 pub struct OutputFormatter {
     quiet: bool,
+    submit: bool,
     start_time: std::time::Instant,
 }
 
 impl OutputFormatter {
     /// Create a new output formatter
-    pub fn new(quiet: bool) -> Self {
+    ///
+    /// `submit` tells whether answers are being submitted, so suspicious answers
+    /// are only reported as held back when a submission was actually attempted.
+    pub fn new(quiet: bool, submit: bool) -> Self {
         Self {
             quiet,
+            submit,
             start_time: std::time::Instant::now(),
         }
     }
@@ -33,6 +42,9 @@ impl OutputFormatter {
             Ok(answer) => println!("{}", answer),
             Err(e) => eprintln!("Error: {}", e),
         }
+        if let Some(e) = &result.validation_error {
+            eprintln!("Warning: {}: {}", self.suspicious_answer(), e);
+        }
     }
 
     /// Print full output with timing and submission info
@@ -41,27 +53,48 @@ impl OutputFormatter {
 
         match &result.answer {
             Ok(answer) => {
+                let arena_info = result
+                    .parse_arena
+                    .filter(|stats| stats.allocations > 0)
+                    .map(|stats| format!(" [{}]", format_arena(stats)))
+                    .unwrap_or_default();
                 let parse_timing = result
                     .parse_duration
-                    .map(|d| format!("parse: {}, ", format_duration(d)))
+                    .map(|d| format!("parse: {}{}, ", format_duration(d), arena_info))
                     .unwrap_or_default();
-                let solve_timing = format_duration(result.solve_duration);
-
-                let submission_info = match &result.submission {
-                    Some(outcome) => {
-                        let time_str = result
-                            .submitted_at
-                            .map(|t| t.format("%H:%M:%S").to_string())
-                            .unwrap_or_default();
-                        format!(", submitted {}: {}", time_str, format_outcome(outcome))
+                let solve_timing = match result.solve_cpu_duration {
+                    Some(cpu) => format!(
+                        "{} [cpu {}]",
+                        format_duration(result.solve_duration),
+                        format_duration(cpu)
+                    ),
+                    None => format_duration(result.solve_duration),
+                };
+
+                let verification_info = match &result.verification {
+                    Some(Verification::Verified) => ", ✓ verified".to_string(),
+                    Some(Verification::Regressed { expected }) => {
+                        format!(", ✗ regressed (expected {})", expected)
                     }
                     None => String::new(),
                 };
+                let submission_info = match (&result.submission, result.submitted_at) {
+                    (Some(outcome), Some(submitted_at)) => format!(
+                        ", submitted {}: {}",
+                        submitted_at.format("%H:%M:%S"),
+                        format_outcome(outcome)
+                    ),
+                    (Some(outcome), None) => format!(", {}", format_outcome(outcome)),
+                    (None, _) => String::new(),
+                };
 
                 println!(
-                    "{}: {} ({}solve: {}{})",
-                    prefix, answer, parse_timing, solve_timing, submission_info
+                    "{}: {} ({}solve: {}{}{})",
+                    prefix, answer, parse_timing, solve_timing, verification_info, submission_info
                 );
+                if let Some(e) = &result.validation_error {
+                    eprintln!("{}: Warning - {}: {}", prefix, self.suspicious_answer(), e);
+                }
             }
             Err(e) => {
                 eprintln!("{}: Error - {}", prefix, e);
@@ -69,6 +102,14 @@ impl OutputFormatter {
         }
     }
 
+    fn suspicious_answer(&self) -> &'static str {
+        if self.submit {
+            "suspicious answer, not submitted"
+        } else {
+            "suspicious answer"
+        }
+    }
+
     /// Print a summary after all results
     /// Shows both total solve time (sum of durations) and actual elapsed wall-clock time
     pub fn print_summary(&self, results: &[SolverResult]) {
@@ -90,14 +131,33 @@ impl OutputFormatter {
             .filter(|r| r.answer.is_ok())
             .map(|r| r.solve_duration)
             .sum();
+        let total_cpu_time: Option<TimeDelta> = results
+            .iter()
+            .filter(|r| r.answer.is_ok())
+            .flat_map(|r| [r.parse_cpu_duration, r.solve_cpu_duration])
+            .sum();
         let total_compute_time = total_parse_time + total_solve_time;
         let elapsed_time = self.start_time.elapsed();
 
         println!();
         println!("--- Summary ---");
         println!("Solvers: {} solved, {} failed", successes, failures);
+        let verified = results
+            .iter()
+            .filter(|r| r.verification == Some(Verification::Verified))
+            .count();
+        let regressed = results
+            .iter()
+            .filter(|r| matches!(r.verification, Some(Verification::Regressed { .. })))
+            .count();
+        if verified + regressed > 0 {
+            println!("Answers: {} verified, {} regressed", verified, regressed);
+        }
         println!("Total parse time: {}", format_duration(total_parse_time));
         println!("Total solve time: {}", format_duration(total_solve_time));
+        if let Some(cpu) = total_cpu_time {
+            println!("Total CPU time: {}", format_duration(cpu));
+        }
         println!(
             "Elapsed wall-clock time: {}",
             format_std_duration(elapsed_time)
@@ -142,16 +202,203 @@ fn format_std_duration(d: std::time::Duration) -> String {
     }
 }
 
+/// Format arena allocation statistics for display
+fn format_arena(stats: ArenaStats) -> String {
+    let bytes = stats.bytes as f64;
+    let size = if bytes < 1024.0 {
+        format!("{}B", stats.bytes)
+    } else if bytes < 1024.0 * 1024.0 {
+        format!("{:.1}KiB", bytes / 1024.0)
+    } else {
+        format!("{:.1}MiB", bytes / (1024.0 * 1024.0))
+    };
+    format!("arena {} allocs, {}", stats.allocations, size)
+}
+
+/// Format private leaderboard standings, one member per line
+pub fn format_standings(board: &PrivateLeaderboard) -> String {
+    board
+        .standings()
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            format!(
+                "{:>3}) {:>5} {:>3}★ {}\n",
+                i + 1,
+                m.local_score,
+                m.stars,
+                m.display_name()
+            )
+        })
+        .collect()
+}
+
+/// Format each member's solve times for a day, measured from the puzzle unlock
+///
+/// Members are ordered by part 2 time, then part 1 time; members without a
+/// star on that day are omitted.
+pub fn format_day_deltas(board: &PrivateLeaderboard, year: u16, day: u8) -> String {
+    let unlock = DateTime::<Utc>::from(unlock_time(year, day));
+    let since_unlock = |ts: i64| TimeDelta::seconds(ts - unlock.timestamp());
+
+    let mut rows: Vec<_> = board
+        .members
+        .values()
+        .filter_map(|m| {
+            let part1 = m.get_star_ts(day, 1)?;
+            Some((m, part1, m.get_star_ts(day, 2)))
+        })
+        .collect();
+    rows.sort_by_key(|&(m, part1, part2)| (part2.is_none(), part2, part1, m.id));
+
+    let mut out = format!(
+        "{:<30} {:>10} {:>10} {:>10}\n",
+        format!("Day {}", day),
+        "part 1",
+        "part 2",
+        "delta"
+    );
+    for (member, part1, part2) in rows {
+        let (part2_str, delta_str) = match part2 {
+            Some(ts) => (
+                format_clock(since_unlock(ts)),
+                format_clock(TimeDelta::seconds(ts - part1)),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        out.push_str(&format!(
+            "{:<30} {:>10} {:>10} {:>10}\n",
+            member.display_name(),
+            format_clock(since_unlock(part1)),
+            part2_str,
+            delta_str
+        ));
+    }
+    out
+}
+
+/// Format a year's stars as a 5x5 grid of days
+///
+/// Collected stars are `★`, uncollected ones on unlocked days `☆`, and locked
+/// days are left blank.
+pub fn format_calendar(progress: &YearProgress) -> String {
+    let mut out = format!("{}: {}/50 ★\n", progress.year, progress.total_stars());
+    for row in (1..=25u8).collect::<Vec<_>>().chunks(5) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|&day| {
+                let stars = if progress.is_unlocked(day) {
+                    let got = usize::from(progress.stars(day));
+                    format!("{}{}", "★".repeat(got), "☆".repeat(2 - got))
+                } else {
+                    "  ".to_string()
+                };
+                format!("{:>2} {}", day, stars)
+            })
+            .collect();
+        out.push_str(cells.join("   ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Format the time left until an unlock, rounded up to whole seconds
+pub fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    format_clock(TimeDelta::seconds(secs as i64))
+}
+
+/// Format a duration as hours:minutes:seconds (hours may exceed 24)
+fn format_clock(d: TimeDelta) -> String {
+    let secs = d.num_seconds().max(0);
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
 /// Format a submission outcome for display
 fn format_outcome(outcome: &SubmissionOutcome) -> String {
     match outcome {
         SubmissionOutcome::Correct => "✓ Correct".to_string(),
-        SubmissionOutcome::Incorrect => "✗ Incorrect".to_string(),
+        SubmissionOutcome::Incorrect { hint } => match hint {
+            Some(AnswerHint::TooHigh) => "✗ Incorrect (too high)".to_string(),
+            Some(AnswerHint::TooLow) => "✗ Incorrect (too low)".to_string(),
+            None => "✗ Incorrect".to_string(),
+        },
         SubmissionOutcome::AlreadyCompleted => "⏭ Already completed".to_string(),
+        SubmissionOutcome::WrongLevel => "⏭ Wrong level (part not currently open)".to_string(),
+        SubmissionOutcome::AlreadyStarred => "⭐ Already starred, not submitted".to_string(),
+        SubmissionOutcome::Refused(refusal) => format!("⛔ Not submitted: {}", refusal),
+        SubmissionOutcome::SessionExpired => "⛔ Not submitted: session expired".to_string(),
         SubmissionOutcome::Throttled { wait_time } => match wait_time {
             Some(d) => format!("⏳ Throttled (wait {})", format_duration(*d)),
             None => "⏳ Throttled".to_string(),
         },
+        SubmissionOutcome::Unknown(text) => format!("? Unrecognised response: {}", text),
         SubmissionOutcome::Error(msg) => format!("⚠ Error: {}", msg),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARD_JSON: &str = r#"{"event":"2023","owner_id":1,"members":{
+        "1":{"id":1,"name":"alice","stars":2,"local_score":4,"last_star_ts":1701407400,
+             "completion_day_level":{"1":{"1":{"get_star_ts":1701407100},"2":{"get_star_ts":1701407400}}}},
+        "2":{"id":2,"name":null,"stars":1,"local_score":1,"last_star_ts":1701410400,
+             "completion_day_level":{"1":{"1":{"get_star_ts":1701410400}}}},
+        "3":{"id":3,"name":"carol","stars":0,"local_score":0}}}"#;
+
+    #[test]
+    fn test_format_standings() {
+        let board: PrivateLeaderboard = serde_json::from_str(BOARD_JSON).unwrap();
+        assert_eq!(
+            format_standings(&board),
+            "  1)     4   2★ alice\n  2)     1   1★ (anonymous user #2)\n  3)     0   0★ carol\n"
+        );
+    }
+
+    #[test]
+    fn test_format_day_deltas() {
+        // 2023-12-01 05:00:00 UTC is 1701406800
+        let board: PrivateLeaderboard = serde_json::from_str(BOARD_JSON).unwrap();
+        let lines: Vec<String> = format_day_deltas(&board, 2023, 1)
+            .lines()
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Day 1 part 1 part 2 delta",
+                "alice 0:05:00 0:10:00 0:05:00",
+                "(anonymous user #2) 1:00:00 - -",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_calendar() {
+        let progress = YearProgress {
+            year: 2023,
+            days: [(1, 2), (2, 1), (3, 0)].into_iter().collect(),
+        };
+        let grid = format_calendar(&progress);
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "2023: 3/50 ★");
+        assert_eq!(lines[1], " 1 ★★    2 ★☆    3 ☆☆    4       5");
+        assert_eq!(lines[5], "21      22      23      24      25");
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(TimeDelta::seconds(59)), "0:00:59");
+        assert_eq!(format_clock(TimeDelta::hours(26)), "26:00:00");
+    }
+
+    #[test]
+    fn test_format_countdown_rounds_up() {
+        assert_eq!(format_countdown(Duration::from_millis(500)), "0:00:01");
+        assert_eq!(format_countdown(Duration::from_secs(3661)), "1:01:01");
+    }
+}
@@ -0,0 +1,404 @@
+//! Generating solver modules for new days from a template
+//!
+//! A solver for `YEAR/DAY` is written to `src/year{year}/day{day:02}.rs` of the
+//! solutions crate. If the crate generates its modules from that layout, as
+//! aoc-solutions does, the year's `year{year}` feature is declared in its
+//! `Cargo.toml`; otherwise the module is declared in `src/year{year}/mod.rs`, and
+//! the year module in `src/lib.rs`. Templates use `{{placeholder}}`s:
+//!
+//! | Placeholder | Value |
+//! |-------------|-------|
+//! | `{{year}}`, `{{day}}` | Puzzle year and day, e.g. `2024` and `5` |
+//! | `{{day_padded}}` | Two-digit day, e.g. `05` |
+//! | `{{name}}` | Solver struct name, e.g. `Day05` |
+//! | `{{module}}` | Module name, e.g. `day05` |
+//! | `{{example_input}}` | First example input as a Rust string literal (`""` if unknown) |
+//! | `{{example_answer}}` | Expected part 1 answer for that example (empty if unknown) |
+
+use crate::error::CliError;
+use std::path::{Path, PathBuf};
+
+/// File name of a custom template inside a template directory
+pub const TEMPLATE_FILE: &str = "day.rs.tmpl";
+
+/// Template used when no template directory is configured
+pub const DEFAULT_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+/// Values substituted into a template
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateVars<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub example_input: &'a str,
+    pub example_answer: &'a str,
+}
+
+impl TemplateVars<'_> {
+    fn get(&self, key: &str) -> Option<String> {
+        let value = match key {
+            "year" => self.year.to_string(),
+            "day" => self.day.to_string(),
+            "day_padded" => format!("{:02}", self.day),
+            "name" => format!("Day{:02}", self.day),
+            "module" => day_module(self.day),
+            "example_input" => raw_string(self.example_input),
+            "example_answer" => self.example_answer.escape_debug().to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+/// Paths written by [`scaffold`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffolded {
+    /// The new solver module
+    pub solver: PathBuf,
+    /// Module files or manifest that gained a declaration
+    pub updated: Vec<PathBuf>,
+}
+
+/// Read `day.rs.tmpl` from `dir`, or the built-in template without one
+pub fn load_template(dir: Option<&Path>) -> Result<String, CliError> {
+    let Some(dir) = dir else {
+        return Ok(DEFAULT_TEMPLATE.to_string());
+    };
+    let path = dir.join(TEMPLATE_FILE);
+    std::fs::read_to_string(&path)
+        .map_err(|e| CliError::Scaffold(format!("cannot read {}: {}", path.display(), e)))
+}
+
+/// Replace every `{{placeholder}}` in `template`, failing on unknown ones
+pub fn render(template: &str, vars: &TemplateVars) -> Result<String, CliError> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| CliError::Scaffold("unterminated '{{' in template".to_string()))?
+            + start;
+        let key = rest[start + 2..end].trim();
+        let value = vars.get(key).ok_or_else(|| {
+            CliError::Scaffold(format!("unknown template placeholder '{{{{{}}}}}'", key))
+        })?;
+        output.push_str(&rest[..start]);
+        output.push_str(&value);
+        rest = &rest[end + 2..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Write a rendered solver into the solutions crate at `solutions_dir` and declare it
+///
+/// A crate whose `lib.rs` includes generated code from `OUT_DIR` is taken to
+/// generate its modules, so only the year feature is declared.
+///
+/// Refuses to replace an existing solver unless `force` is set.
+pub fn scaffold(
+    solutions_dir: &Path,
+    source: &str,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<Scaffolded, CliError> {
+    let src = solutions_dir.join("src");
+    let lib = src.join("lib.rs");
+    if !lib.is_file() {
+        return Err(CliError::Scaffold(format!(
+            "{} is not a crate: {} is missing",
+            solutions_dir.display(),
+            lib.display()
+        )));
+    }
+
+    let year_module = format!("year{}", year);
+    let year_dir = src.join(&year_module);
+    let solver = year_dir.join(format!("{}.rs", day_module(day)));
+    if solver.exists() && !force {
+        return Err(CliError::Scaffold(format!(
+            "{} already exists (use --force to overwrite)",
+            solver.display()
+        )));
+    }
+
+    std::fs::create_dir_all(&year_dir)?;
+    std::fs::write(&solver, source)?;
+
+    let mut updated = Vec::new();
+    let lib_source = std::fs::read_to_string(&lib)?;
+    if lib_source.contains("OUT_DIR") {
+        let manifest = solutions_dir.join("Cargo.toml");
+        if let Some(source) = add_feature(&std::fs::read_to_string(&manifest)?, &year_module) {
+            std::fs::write(&manifest, source)?;
+            updated.push(manifest);
+        }
+        return Ok(Scaffolded { solver, updated });
+    }
+
+    let year_mod = year_dir.join("mod.rs");
+    let year_source = match std::fs::read_to_string(&year_mod) {
+        Ok(source) => source,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            format!("//! Solutions for Advent of Code {}\n", year)
+        }
+        Err(e) => return Err(e.into()),
+    };
+    for (path, source, module) in [
+        (&year_mod, year_source, day_module(day)),
+        (&lib, lib_source, year_module),
+    ] {
+        if let Some(source) = add_module(&source, &module) {
+            std::fs::write(path, source)?;
+            updated.push(path.clone());
+        }
+    }
+
+    Ok(Scaffolded { solver, updated })
+}
+
+/// Add `pub mod {module};` to a module file, keeping declarations sorted
+///
+/// Returns `None` if the module is already declared.
+pub fn add_module(source: &str, module: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    if lines
+        .iter()
+        .any(|line| declared_module(line) == Some(module))
+    {
+        return None;
+    }
+
+    let declaration = format!("pub mod {};", module);
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| declared_module(lines[i]).is_some())
+        .collect();
+    let mut output: Vec<&str> = lines.clone();
+    match mods
+        .iter()
+        .find(|&&i| declared_module(lines[i]) > Some(module))
+    {
+        Some(&i) => output.insert(i, &declaration),
+        None => match mods.last() {
+            Some(&i) => output.insert(i + 1, &declaration),
+            None => {
+                if output.last().is_some_and(|line| !line.trim().is_empty()) {
+                    output.push("");
+                }
+                output.push(&declaration);
+            }
+        },
+    }
+    Some(output.join("\n") + "\n")
+}
+
+/// Add `{feature} = []` to the `[features]` table of a manifest, keeping year features sorted
+///
+/// Returns `None` if the feature is already declared.
+pub fn add_feature(manifest: &str, feature: &str) -> Option<String> {
+    let feature_name = |line: &str| {
+        line.split_once('=')
+            .map(|(name, _)| name.trim().to_string())
+    };
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let declaration = format!("{} = []", feature);
+
+    let Some(header) = lines.iter().position(|line| line.trim() == "[features]") else {
+        if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+            lines.push(String::new());
+        }
+        lines.push("[features]".to_string());
+        lines.push(declaration);
+        return Some(lines.join("\n") + "\n");
+    };
+    let end = (header + 1..lines.len())
+        .find(|&i| lines[i].trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+    let names: Vec<(usize, String)> = (header + 1..end)
+        .filter_map(|i| Some((i, feature_name(&lines[i])?)))
+        .collect();
+    if names.iter().any(|(_, name)| name == feature) {
+        return None;
+    }
+
+    let years: Vec<&(usize, String)> = names
+        .iter()
+        .filter(|(_, name)| name.starts_with("year"))
+        .collect();
+    let at = years
+        .iter()
+        .find(|(_, name)| name.as_str() > feature)
+        .map(|(i, _)| *i)
+        .or_else(|| years.last().map(|(i, _)| i + 1))
+        .or_else(|| names.last().map(|(i, _)| i + 1))
+        .unwrap_or(header + 1);
+    lines.insert(at, declaration);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Name of the module a `mod name;` line declares, with any visibility
+fn declared_module(line: &str) -> Option<&str> {
+    let line = match line.strip_prefix("pub(") {
+        Some(rest) => rest.split_once(')')?.1.trim_start(),
+        None => line.trim_start_matches("pub "),
+    };
+    line.strip_prefix("mod ")
+        .and_then(|rest| rest.strip_suffix(';'))
+        .map(str::trim)
+}
+
+/// Module name of a day, e.g. `day05`
+fn day_module(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Quote text as a raw string literal with enough `#`s to hold it
+fn raw_string(text: &str) -> String {
+    let hashes = (0..)
+        .find(|&n| !text.contains(&format!("\"{}", "#".repeat(n))))
+        .unwrap_or(0);
+    let hashes = "#".repeat(hashes);
+    format!("r{}\"{}\"{}", hashes, text, hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn vars<'a>(example_input: &'a str, example_answer: &'a str) -> TemplateVars<'a> {
+        TemplateVars {
+            year: 2024,
+            day: 5,
+            example_input,
+            example_answer,
+        }
+    }
+
+    #[test]
+    fn test_render_placeholders() {
+        let rendered = render(
+            "{{year}}/{{ day }} {{day_padded}} {{name}} {{module}} {{example_input}} \"{{example_answer}}\"",
+            &vars("a\n\"b\"", "42"),
+        )
+        .unwrap();
+        assert_eq!(rendered, "2024/5 05 Day05 day05 r#\"a\n\"b\"\"# \"42\"");
+
+        assert!(render("{{nope}}", &vars("", "")).is_err());
+        assert!(render("{{year", &vars("", "")).is_err());
+    }
+
+    #[test]
+    fn test_default_template_renders() {
+        let rendered = render(DEFAULT_TEMPLATE, &vars("1\n2\n", "3")).unwrap();
+        assert!(rendered.contains("#[aoc(year = 2024, day = 5)]"));
+        assert!(rendered.contains("pub struct Day05;"));
+        assert!(rendered.contains("const EXAMPLE: &str = r\"1\n2\n\";"));
+        assert!(rendered.contains("#[ignore"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn test_add_module_keeps_declarations_sorted() {
+        let lib = "//! Solutions\n\npub mod stress_test;\n";
+        let lib = add_module(lib, "year2024").unwrap();
+        assert_eq!(
+            lib,
+            "//! Solutions\n\npub mod stress_test;\npub mod year2024;\n"
+        );
+        let lib = add_module(&lib, "year2023").unwrap();
+        assert_eq!(
+            lib,
+            "//! Solutions\n\npub mod stress_test;\npub mod year2023;\npub mod year2024;\n"
+        );
+        assert_eq!(add_module(&lib, "year2023"), None);
+
+        assert_eq!(
+            add_module("//! Year\n", "day01").unwrap(),
+            "//! Year\n\npub mod day01;\n"
+        );
+    }
+
+    #[test]
+    fn test_declared_module_accepts_any_visibility() {
+        assert_eq!(declared_module("mod day01;"), Some("day01"));
+        assert_eq!(declared_module("pub mod day01;"), Some("day01"));
+        assert_eq!(declared_module("pub(crate) mod day01;"), Some("day01"));
+        assert_eq!(
+            declared_module("pub(in crate::y) mod day01;"),
+            Some("day01")
+        );
+        assert_eq!(declared_module("use day01;"), None);
+
+        let lib = "pub(crate) mod day01;\npub(crate) mod day03;\n";
+        assert_eq!(add_module(lib, "day01"), None);
+        assert_eq!(
+            add_module(lib, "day02").unwrap(),
+            "pub(crate) mod day01;\npub mod day02;\npub(crate) mod day03;\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold_writes_and_declares_modules() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("lib.rs"), "//! Solutions\n").unwrap();
+
+        let scaffolded = scaffold(temp.path(), "// day 5\n", 2024, 5, false).unwrap();
+        assert_eq!(scaffolded.solver, src.join("year2024/day05.rs"));
+        assert_eq!(scaffolded.updated.len(), 2);
+        assert_eq!(
+            std::fs::read_to_string(src.join("year2024/mod.rs")).unwrap(),
+            "//! Solutions for Advent of Code 2024\n\npub mod day05;\n"
+        );
+        assert!(
+            std::fs::read_to_string(src.join("lib.rs"))
+                .unwrap()
+                .ends_with("pub mod year2024;\n")
+        );
+
+        // An existing solver is kept unless forced
+        assert!(scaffold(temp.path(), "// other\n", 2024, 5, false).is_err());
+        let scaffolded = scaffold(temp.path(), "// other\n", 2024, 5, true).unwrap();
+        assert!(scaffolded.updated.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(src.join("year2024/day05.rs")).unwrap(),
+            "// other\n"
+        );
+
+        assert!(scaffold(&temp.path().join("missing"), "", 2024, 6, false).is_err());
+    }
+
+    #[test]
+    fn test_scaffold_declares_feature_for_generated_modules() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        let lib = "include!(concat!(env!(\"OUT_DIR\"), \"/solutions.rs\"));\n";
+        std::fs::write(src.join("lib.rs"), lib).unwrap();
+        std::fs::write(temp.path().join("Cargo.toml"), "[package]\nname = \"s\"\n").unwrap();
+
+        let scaffolded = scaffold(temp.path(), "// day 5\n", 2024, 5, false).unwrap();
+        assert_eq!(scaffolded.updated, [temp.path().join("Cargo.toml")]);
+        assert!(!src.join("year2024/mod.rs").exists());
+        assert_eq!(std::fs::read_to_string(src.join("lib.rs")).unwrap(), lib);
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("Cargo.toml")).unwrap(),
+            "[package]\nname = \"s\"\n\n[features]\nyear2024 = []\n"
+        );
+    }
+
+    #[test]
+    fn test_add_feature_keeps_years_sorted() {
+        let manifest = "[features]\ndefault = [\"all-years\"]\nall-years = []\n\n[dependencies]\n";
+        let manifest = add_feature(manifest, "year2024").unwrap();
+        assert_eq!(
+            manifest,
+            "[features]\ndefault = [\"all-years\"]\nall-years = []\nyear2024 = []\n\n[dependencies]\n"
+        );
+        let manifest = add_feature(&manifest, "year2015").unwrap();
+        assert!(manifest.contains("all-years = []\nyear2015 = []\nyear2024 = []\n"));
+        assert_eq!(add_feature(&manifest, "year2015"), None);
+    }
+}
@@ -0,0 +1,326 @@
+//! Encrypted on-disk store of named session profiles
+//!
+//! The store is a single file: the magic bytes `AOCSESS1`, a 16 byte salt, a 12
+//! byte nonce and the ChaCha20-Poly1305 encryption of one
+//! `{profile}<TAB>{user_id}<TAB>{session}` line per profile. The key is derived
+//! with Argon2id from a passphrase or from the contents of a key file, and a
+//! fresh salt and nonce are used for every save.
+
+use crate::error::StoreError;
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+/// Profile used when `--profile` and `AOC_PROFILE` are not set
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// File name of the store inside the cache directory
+pub const STORE_FILE: &str = "sessions.enc";
+
+const MAGIC: &[u8] = b"AOCSESS1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// Size of a generated key file
+const KEY_FILE_LEN: usize = 32;
+
+/// A saved session and the user it belongs to
+pub struct Profile {
+    pub session: Zeroizing<String>,
+    pub user_id: u64,
+}
+
+/// Secret the store key is derived from
+pub enum StoreKey {
+    /// A passphrase typed by the user or taken from `AOC_STORE_PASSPHRASE`
+    Passphrase(Zeroizing<String>),
+    /// A file whose whole contents are the secret
+    KeyFile(PathBuf),
+}
+
+impl StoreKey {
+    /// Read the secret bytes
+    fn secret(&self) -> Result<Zeroizing<Vec<u8>>, StoreError> {
+        let secret = match self {
+            StoreKey::Passphrase(passphrase) => Zeroizing::new(passphrase.as_bytes().to_vec()),
+            StoreKey::KeyFile(path) => Zeroizing::new(fs::read(path)?),
+        };
+        if secret.is_empty() {
+            return Err(StoreError::Format(
+                "empty passphrase or key file".to_string(),
+            ));
+        }
+        Ok(secret)
+    }
+
+    /// Derive the encryption key for a salt
+    fn derive(&self, salt: &[u8]) -> Result<Zeroizing<[u8; 32]>, StoreError> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        Argon2::default()
+            .hash_password_into(&self.secret()?, salt, key.as_mut())
+            .map_err(|e| StoreError::Format(e.to_string()))?;
+        Ok(key)
+    }
+}
+
+/// Create a key file with random contents, unless it already exists
+///
+/// Returns whether a new file was written.
+pub fn create_key_file(path: &Path) -> Result<bool, StoreError> {
+    if path.exists() {
+        return Ok(false);
+    }
+    let mut secret = Zeroizing::new([0u8; KEY_FILE_LEN]);
+    OsRng.fill_bytes(secret.as_mut());
+    write_private(path, secret.as_ref())?;
+    Ok(true)
+}
+
+/// Named profiles, each holding a session and user ID
+#[derive(Default)]
+pub struct SessionStore {
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl SessionStore {
+    /// Decrypt the store at `path`, or start an empty one if it does not exist
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::Locked` if the key is wrong or the file was modified.
+    pub fn load(path: &Path, key: &StoreKey) -> Result<Self, StoreError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = fs::read(path)?;
+        let header = MAGIC.len() + SALT_LEN + NONCE_LEN;
+        if data.len() < header || !data.starts_with(MAGIC) {
+            return Err(StoreError::Format(format!(
+                "{} is not a session store",
+                path.display()
+            )));
+        }
+        let (salt, rest) = data[MAGIC.len()..].split_at(SALT_LEN);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+        let key = key.derive(salt)?;
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map(Zeroizing::new)
+            .map_err(|_| StoreError::Locked)?;
+        let plaintext = std::str::from_utf8(&plaintext)
+            .map_err(|_| StoreError::Format("profiles are not UTF-8".to_string()))?;
+        Self::parse(plaintext)
+    }
+
+    /// Encrypt the store to `path` with a fresh salt and nonce
+    pub fn save(&self, path: &Path, key: &StoreKey) -> Result<(), StoreError> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let key = key.derive(&salt)?;
+        let plaintext = self.to_plaintext();
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .map_err(|_| StoreError::Format("encryption failed".to_string()))?;
+
+        let mut data = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+        data.extend_from_slice(MAGIC);
+        data.extend_from_slice(&salt);
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+        write_private(path, &data)
+    }
+
+    /// Add or replace a profile
+    pub fn insert(&mut self, name: &str, profile: Profile) -> Result<(), StoreError> {
+        if name.is_empty() || name.contains(['\t', '\n', '\r']) {
+            return Err(StoreError::Format(format!(
+                "invalid profile name '{}'",
+                name
+            )));
+        }
+        // Sessions are stored one per line, tab-separated from the profile name
+        if profile.session.is_empty() || profile.session.contains(['\t', '\n', '\r']) {
+            return Err(StoreError::Format(
+                "invalid session: must be non-empty without tabs or line breaks".to_string(),
+            ));
+        }
+        self.profiles.insert(name.to_string(), profile);
+        Ok(())
+    }
+
+    /// Remove a profile, returning it if it existed
+    pub fn remove(&mut self, name: &str) -> Option<Profile> {
+        self.profiles.remove(name)
+    }
+
+    /// Whether the store holds no profiles
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    fn parse(plaintext: &str) -> Result<Self, StoreError> {
+        let mut profiles = BTreeMap::new();
+        for line in plaintext.lines().filter(|line| !line.is_empty()) {
+            let mut fields = line.splitn(3, '\t');
+            let (Some(name), Some(user_id), Some(session)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(StoreError::Format("malformed profile entry".to_string()));
+            };
+            let user_id = user_id
+                .parse()
+                .map_err(|_| StoreError::Format(format!("invalid user ID for '{}'", name)))?;
+            profiles.insert(
+                name.to_string(),
+                Profile {
+                    session: Zeroizing::new(session.to_string()),
+                    user_id,
+                },
+            );
+        }
+        Ok(Self { profiles })
+    }
+
+    fn to_plaintext(&self) -> Zeroizing<String> {
+        let mut plaintext = Zeroizing::new(String::new());
+        for (name, profile) in &self.profiles {
+            plaintext.push_str(name);
+            plaintext.push('\t');
+            plaintext.push_str(&profile.user_id.to_string());
+            plaintext.push('\t');
+            plaintext.push_str(&profile.session);
+            plaintext.push('\n');
+        }
+        plaintext
+    }
+}
+
+/// Write a file readable only by the current user where the OS supports it
+///
+/// Each write goes to a temporary file of its own that is renamed into place, so
+/// concurrent writers cannot mix their contents.
+fn write_private(path: &Path, contents: &[u8]) -> Result<(), StoreError> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+    let mut builder = tempfile::Builder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(fs::Permissions::from_mode(0o600));
+    }
+    let mut tmp = builder.tempfile_in(dir)?;
+    std::io::Write::write_all(&mut tmp, contents)?;
+    tmp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn passphrase(s: &str) -> StoreKey {
+        StoreKey::Passphrase(Zeroizing::new(s.to_string()))
+    }
+
+    fn profile(session: &str, user_id: u64) -> Profile {
+        Profile {
+            session: Zeroizing::new(session.to_string()),
+            user_id,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_with_passphrase() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(STORE_FILE);
+
+        let mut store = SessionStore::default();
+        store.insert("default", profile("abc123", 42)).unwrap();
+        store.insert("work", profile("def456", 7)).unwrap();
+        store.save(&path, &passphrase("hunter2")).unwrap();
+
+        let raw = fs::read(&path).unwrap();
+        assert!(raw.starts_with(MAGIC));
+        assert!(!raw.windows(6).any(|w| w == b"abc123"));
+
+        let mut loaded = SessionStore::load(&path, &passphrase("hunter2")).unwrap();
+        let work = loaded.remove("work").unwrap();
+        assert_eq!((work.session.as_str(), work.user_id), ("def456", 7));
+        assert_eq!(loaded.remove("default").unwrap().user_id, 42);
+    }
+
+    #[test]
+    fn test_wrong_key_or_tampering_is_locked() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(STORE_FILE);
+        let mut store = SessionStore::default();
+        store.insert("default", profile("abc123", 42)).unwrap();
+        store.save(&path, &passphrase("right")).unwrap();
+
+        assert!(matches!(
+            SessionStore::load(&path, &passphrase("wrong")),
+            Err(StoreError::Locked)
+        ));
+
+        let mut raw = fs::read(&path).unwrap();
+        *raw.last_mut().unwrap() ^= 1;
+        fs::write(&path, raw).unwrap();
+        assert!(matches!(
+            SessionStore::load(&path, &passphrase("right")),
+            Err(StoreError::Locked)
+        ));
+    }
+
+    #[test]
+    fn test_key_file() {
+        let temp = TempDir::new().unwrap();
+        let key_path = temp.path().join("store.key");
+        let path = temp.path().join(STORE_FILE);
+
+        assert!(create_key_file(&key_path).unwrap());
+        assert!(!create_key_file(&key_path).unwrap());
+        let key = StoreKey::KeyFile(key_path.clone());
+
+        let mut store = SessionStore::default();
+        store.insert("default", profile("abc123", 42)).unwrap();
+        store.save(&path, &key).unwrap();
+        let mut loaded = SessionStore::load(&path, &key).unwrap();
+        assert_eq!(loaded.remove("default").unwrap().user_id, 42);
+        assert!(loaded.is_empty());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            for file in [&key_path, &path] {
+                let mode = fs::metadata(file).unwrap().permissions().mode();
+                assert_eq!(mode & 0o777, 0o600);
+            }
+        }
+        // Only the key and the store are left, without temporary files
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_missing_store_is_empty_and_bad_names_are_rejected() {
+        let temp = TempDir::new().unwrap();
+        let mut store =
+            SessionStore::load(&temp.path().join(STORE_FILE), &passphrase("x")).unwrap();
+        assert!(store.is_empty());
+        assert!(store.insert("a\tb", profile("s", 1)).is_err());
+        assert!(store.insert("", profile("s", 1)).is_err());
+        assert!(store.insert("a", profile("s\tt", 1)).is_err());
+        assert!(store.insert("a", profile("s\nt", 1)).is_err());
+    }
+}
@@ -16,29 +16,33 @@ use std::time::Duration;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get session cookie from environment variable
     let session = std::env::var("AOC_SESSION").expect("AOC_SESSION environment variable not set");
+    // Identify yourself to AOC in the User-Agent header
+    let contact = std::env::var("AOC_CONTACT").expect("AOC_CONTACT environment variable not set");
 
     // Example 1: Create a client with default settings
     println!("=== Example 1: Default Client ===");
-    let client = AocClient::new()?;
+    let client = AocClient::new(&contact)?;
     println!("✓ Client created with default base URL (https://adventofcode.com)");
 
     // Example 2: Create a client with custom base URL (useful for testing)
     println!("\n=== Example 2: Custom Base URL ===");
-    let _custom_client = AocClient::builder()
+    let _custom_client = AocClient::builder(&contact)
         .base_url("https://adventofcode.com")? // Could be a mock server URL for testing
         .build()?;
     println!("✓ Client created with custom base URL");
 
     // Example 3: Create a client with custom HTTP configuration
     println!("\n=== Example 3: Custom HTTP Configuration ===");
-    let _configured_client = AocClient::builder()
+    let _configured_client = AocClient::builder(&contact)
         .client_builder(
             reqwest::blocking::Client::builder()
                 .timeout(Duration::from_secs(30))
                 .use_rustls_tls(),
         )
+        .min_request_interval(Duration::from_secs(1))
+        .max_retries(5)
         .build()?;
-    println!("✓ Client created with custom timeout (30s)");
+    println!("✓ Client created with custom timeout (30s), pacing and retries");
 
     // Use the default client for the rest of the example
     println!("\n=== Using Default Client ===");
@@ -80,12 +84,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             SubmissionResult::Correct => {
                 println!("✓ Answer is correct!");
             }
-            SubmissionResult::Incorrect => {
-                println!("✗ Answer is incorrect");
-            }
+            SubmissionResult::Incorrect { hint } => match hint {
+                Some(hint) => println!("✗ Answer is incorrect ({:?})", hint),
+                None => println!("✗ Answer is incorrect"),
+            },
             SubmissionResult::AlreadyCompleted => {
                 println!("ℹ Problem already completed");
             }
+            SubmissionResult::WrongLevel => {
+                println!("ℹ Not the level currently being solved");
+            }
             SubmissionResult::Throttled { wait_time } => {
                 if let Some(duration) = wait_time {
                     println!("⏱ Throttled. Wait time: {:?}", duration);
@@ -93,6 +101,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("⏱ Throttled. Wait time unknown");
                 }
             }
+            SubmissionResult::Unknown(text) => {
+                println!("? Unrecognised response: {}", text);
+            }
         },
         Err(e) => {
             println!("✗ Failed to submit answer: {}", e);
@@ -0,0 +1,33 @@
+//! Record real adventofcode.com exchanges as fixture files
+//!
+//! Fetches the settings page and the input and puzzle pages of one day, saving
+//! each exchange with its cookies redacted. AoC asks that inputs and puzzle text
+//! are not redistributed, so review the files (and replace the input with the
+//! example) before copying them into `tests/fixtures/http`.
+//!
+//! Usage: `AOC_SESSION=... AOC_CONTACT=... cargo run --example record_fixtures -- <dir> 2023 1`
+
+use aoc_http_client::{AocClient, FixtureMode};
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let session = std::env::var("AOC_SESSION").expect("AOC_SESSION environment variable not set");
+    let contact = std::env::var("AOC_CONTACT").expect("AOC_CONTACT environment variable not set");
+    let mut args = std::env::args().skip(1);
+    let dir = args
+        .next()
+        .expect("usage: record_fixtures <dir> [year] [day]");
+    let year: u16 = args.next().unwrap_or_else(|| "2023".into()).parse()?;
+    let day: u8 = args.next().unwrap_or_else(|| "1".into()).parse()?;
+
+    let client = AocClient::builder(&contact)
+        .min_request_interval(Duration::from_secs(1))
+        .fixtures(FixtureMode::Record(dir.clone().into()))
+        .build()?;
+
+    client.verify_session(&session)?;
+    client.get_input(year, day, &session)?;
+    client.get_puzzle(year, day, &session)?;
+    println!("Recorded {}/{:02} into {}", year, day, dir);
+    Ok(())
+}
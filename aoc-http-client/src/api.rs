@@ -0,0 +1,58 @@
+//! The AOC operations needed to run solvers, as a trait
+//!
+//! Code written against [`AocApi`] instead of [`AocClient`] can be exercised with
+//! [`FakeAocApi`](crate::FakeAocApi) (feature `fake`) instead of an HTTP mock.
+
+use crate::calendar::YearProgress;
+use crate::client::{AocClient, SessionInfo, SubmissionResult};
+use crate::error::AocError;
+
+/// Session verification, input fetching, answer submission and calendar progress
+///
+/// Implemented by [`AocClient`]. Implementations are shared between worker
+/// threads, so they must be `Send + Sync`.
+pub trait AocApi: Send + Sync {
+    /// See [`AocClient::verify_session`]
+    fn verify_session(&self, session: &str) -> Result<SessionInfo, AocError>;
+
+    /// See [`AocClient::get_input`]
+    fn get_input(&self, year: u16, day: u8, session: &str) -> Result<String, AocError>;
+
+    /// See [`AocClient::submit_answer`]
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        session: &str,
+    ) -> Result<SubmissionResult, AocError>;
+
+    /// See [`AocClient::get_calendar`]
+    fn get_calendar(&self, year: u16, session: &str) -> Result<YearProgress, AocError>;
+}
+
+impl AocApi for AocClient {
+    fn verify_session(&self, session: &str) -> Result<SessionInfo, AocError> {
+        AocClient::verify_session(self, session)
+    }
+
+    fn get_input(&self, year: u16, day: u8, session: &str) -> Result<String, AocError> {
+        AocClient::get_input(self, year, day, session)
+    }
+
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        session: &str,
+    ) -> Result<SubmissionResult, AocError> {
+        AocClient::submit_answer(self, year, day, part, answer, session)
+    }
+
+    fn get_calendar(&self, year: u16, session: &str) -> Result<YearProgress, AocError> {
+        AocClient::get_calendar(self, year, session)
+    }
+}
@@ -0,0 +1,346 @@
+//! Async AOC HTTP client, enabled by the `async` feature
+
+use crate::client::{SessionInfo, SubmissionResult};
+use crate::error::AocError;
+use crate::parser::{ResponseParser, check_input};
+use crate::request::{self, RateLimiter, RetryPolicy};
+use reqwest::{Method, RequestBuilder, Response};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Async counterpart of [`AocClient`](crate::AocClient)
+///
+/// Shares the response parsing, errors, User-Agent policy, request pacing and
+/// retry behaviour of the blocking client, but never blocks the calling thread.
+/// Must be used from within a Tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// use aoc_http_client::AsyncAocClient;
+///
+/// # async fn run() -> Result<(), aoc_http_client::AocError> {
+/// let client = AsyncAocClient::new("github.com/me/aoc by me@example.com")?;
+/// let session = "your_session_cookie";
+///
+/// if let Some(user_id) = client.verify_session(session).await?.user_id {
+///     println!("Session valid! User ID: {}", user_id);
+/// }
+/// let input = client.get_input(2024, 1, session).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AsyncAocClient {
+    client: reqwest::Client,
+    base_url: reqwest::Url,
+    parser: ResponseParser,
+    rate_limiter: Arc<RateLimiter>,
+    retry: RetryPolicy,
+}
+
+impl AsyncAocClient {
+    /// Create a new async client with default settings
+    ///
+    /// `contact` is sent in the User-Agent header, as for
+    /// [`AocClient::new`](crate::AocClient::new).
+    ///
+    /// # Errors
+    ///
+    /// Returns `AocError::ClientInit` if the HTTP client cannot be initialized or
+    /// the contact is empty.
+    pub fn new(contact: &str) -> Result<Self, AocError> {
+        Self::builder(contact).build()
+    }
+
+    /// Create a builder for configuring the async client
+    pub fn builder(contact: impl Into<String>) -> AsyncAocClientBuilder {
+        AsyncAocClientBuilder::new(contact)
+    }
+
+    /// Send a request, pacing it and retrying transient failures
+    ///
+    /// Follows the same retry rules as the blocking client: GET requests are
+    /// retried on connection errors, timeouts and 5xx responses, POST requests
+    /// only on connection errors, and 4xx responses never.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, AocError> {
+        let request = request.build()?;
+        let idempotent = request.method() == Method::GET;
+        let mut attempt = 0;
+        loop {
+            let attempt_request = request
+                .try_clone()
+                .expect("requests without streaming bodies can be cloned");
+            tokio::time::sleep(self.rate_limiter.reserve()).await;
+            let result = self.client.execute(attempt_request).await;
+            let transient =
+                request::is_transient(idempotent, &result.as_ref().map(Response::status));
+            if !transient || attempt >= self.retry.max_retries {
+                return result.map_err(AocError::from);
+            }
+            tokio::time::sleep(self.retry.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Verify if a session cookie is valid and retrieve user ID
+    ///
+    /// See [`AocClient::verify_session`](crate::AocClient::verify_session).
+    pub async fn verify_session(&self, session: &str) -> Result<SessionInfo, AocError> {
+        let cookie_header = request::cookie_header(session)?;
+        let url = request::endpoint(&self.base_url, &["settings"])?;
+
+        let response = self
+            .send(self.client.get(url).header("Cookie", cookie_header))
+            .await?;
+
+        // 2xx success means valid session, 3xx redirect means invalid session
+        if !response.status().is_success() {
+            return Ok(SessionInfo { user_id: None });
+        }
+
+        let html = response.text().await.map_err(|_| AocError::Encoding)?;
+        let user_id = self.parser.extract_user_id(&html);
+
+        Ok(SessionInfo { user_id })
+    }
+
+    /// Fetch puzzle input for a specific year and day
+    ///
+    /// See [`AocClient::get_input`](crate::AocClient::get_input).
+    pub async fn get_input(&self, year: u16, day: u8, session: &str) -> Result<String, AocError> {
+        let cookie_header = request::cookie_header(session)?;
+        let url = request::endpoint(
+            &self.base_url,
+            &[&year.to_string(), "day", &day.to_string(), "input"],
+        )?;
+
+        let response = self
+            .send(self.client.get(url).header("Cookie", cookie_header))
+            .await?;
+        let status = response.status();
+        let body = response.text().await.map_err(|_| AocError::Encoding);
+
+        if let Ok(body) = &body {
+            check_input(body)?;
+        }
+        if !status.is_success() {
+            return Err(AocError::InvalidStatus { status });
+        }
+        body
+    }
+
+    /// Submit an answer for a specific puzzle part
+    ///
+    /// See [`AocClient::submit_answer`](crate::AocClient::submit_answer).
+    pub async fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        session: &str,
+    ) -> Result<SubmissionResult, AocError> {
+        let cookie_header = request::cookie_header(session)?;
+        let url = request::endpoint(
+            &self.base_url,
+            &[&year.to_string(), "day", &day.to_string(), "answer"],
+        )?;
+
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+
+        let response = self
+            .send(
+                self.client
+                    .post(url)
+                    .header("Cookie", cookie_header)
+                    .form(&form),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AocError::InvalidStatus {
+                status: response.status(),
+            });
+        }
+
+        let html = response.text().await.map_err(|_| AocError::Encoding)?;
+        self.parser.parse_submission_response(&html)
+    }
+}
+
+/// Builder for configuring an [`AsyncAocClient`]
+///
+/// Mirrors [`AocClientBuilder`](crate::AocClientBuilder), taking an async
+/// `reqwest::ClientBuilder` for custom HTTP configuration.
+#[derive(Debug)]
+pub struct AsyncAocClientBuilder {
+    contact: String,
+    base_url: Option<reqwest::Url>,
+    client_builder: Option<reqwest::ClientBuilder>,
+    min_request_interval: Duration,
+    retry: RetryPolicy,
+}
+
+impl AsyncAocClientBuilder {
+    /// Create a new builder with default settings
+    pub fn new(contact: impl Into<String>) -> Self {
+        Self {
+            contact: contact.into(),
+            base_url: None,
+            client_builder: None,
+            min_request_interval: Duration::ZERO,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Set a custom base URL for the client (useful for testing)
+    pub fn base_url(mut self, url: impl reqwest::IntoUrl) -> Result<Self, AocError> {
+        self.base_url = Some(url.into_url()?);
+        Ok(self)
+    }
+
+    /// Set a custom HTTP client builder
+    ///
+    /// The redirect policy and User-Agent are always overridden.
+    pub fn client_builder(mut self, builder: reqwest::ClientBuilder) -> Self {
+        self.client_builder = Some(builder);
+        self
+    }
+
+    /// Set the minimum time between the start of two requests, shared by all clones
+    pub fn min_request_interval(mut self, interval: Duration) -> Self {
+        self.min_request_interval = interval;
+        self
+    }
+
+    /// Set how many times a transient failure is retried
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry.max_retries = retries;
+        self
+    }
+
+    /// Set the delay before the first retry, doubled for each further retry
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.retry.initial_backoff = backoff;
+        self
+    }
+
+    /// Build the async client with the configured settings
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contact is empty or the HTTP client cannot be
+    /// initialized.
+    pub fn build(self) -> Result<AsyncAocClient, AocError> {
+        let user_agent = request::user_agent(&self.contact)?;
+        let base_url = self.base_url.unwrap_or_else(request::default_base_url);
+
+        let builder = self
+            .client_builder
+            .unwrap_or_else(|| reqwest::Client::builder().use_rustls_tls());
+
+        // Always override redirect policy to none for session verification
+        let client = builder
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(user_agent)
+            .build()
+            .map_err(|e| AocError::ClientInit(e.to_string()))?;
+
+        Ok(AsyncAocClient {
+            client,
+            base_url,
+            parser: ResponseParser::new(),
+            rate_limiter: Arc::new(RateLimiter::new(self.min_request_interval)),
+            retry: self.retry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CONTACT: &str = "aoc-http-client tests";
+
+    async fn client(server: &mockito::ServerGuard) -> AsyncAocClient {
+        AsyncAocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .initial_backoff(Duration::from_millis(1))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_verify_session() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/settings")
+            .match_header("cookie", "session=abc")
+            .with_status(200)
+            .with_body("<html><body>(anonymous user #42)</body></html>")
+            .create_async()
+            .await;
+
+        let info = client(&server).await.verify_session("abc").await.unwrap();
+        assert_eq!(info.user_id, Some(42));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_input_sends_user_agent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/2023/day/5/input")
+            .match_header(
+                "user-agent",
+                mockito::Matcher::Regex(r"\(aoc-http-client tests\)$".into()),
+            )
+            .with_status(200)
+            .with_body("seeds: 79 14")
+            .create_async()
+            .await;
+
+        let input = client(&server).await.get_input(2023, 5, "abc").await;
+        assert_eq!(input.unwrap(), "seeds: 79 14");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_input_retries_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/2023/day/5/input")
+            .with_status(500)
+            .expect(4)
+            .create_async()
+            .await;
+
+        let result = client(&server).await.get_input(2023, 5, "abc").await;
+        assert!(matches!(result, Err(AocError::InvalidStatus { status }) if status == 500));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_submit_answer() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/2023/day/5/answer")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("level".into(), "2".into()),
+                mockito::Matcher::UrlEncoded("answer".into(), "46".into()),
+            ]))
+            .with_status(200)
+            .with_body("<html><body><main>That's the right answer!</main></body></html>")
+            .create_async()
+            .await;
+
+        let result = client(&server)
+            .await
+            .submit_answer(2023, 5, 2, "46", "abc")
+            .await;
+        assert_eq!(result.unwrap(), SubmissionResult::Correct);
+        mock.assert_async().await;
+    }
+}
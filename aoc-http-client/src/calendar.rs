@@ -0,0 +1,37 @@
+//! Star progress parsed from a year's calendar page
+
+use std::collections::BTreeMap;
+
+/// Stars collected on each unlocked day of one event year
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct YearProgress {
+    /// The AOC year
+    pub year: u16,
+    /// Stars (0-2) keyed by day, for every day shown on the calendar
+    pub days: BTreeMap<u8, u8>,
+}
+
+impl YearProgress {
+    /// Number of stars collected on a day (0 for locked or unknown days)
+    pub fn stars(&self, day: u8) -> u8 {
+        self.days.get(&day).copied().unwrap_or(0)
+    }
+
+    /// Whether a part has been completed
+    ///
+    /// Part 2 can only be starred after part 1, so a day with `n` stars has
+    /// completed parts `1..=n`.
+    pub fn is_completed(&self, day: u8, part: u8) -> bool {
+        part >= 1 && part <= self.stars(day)
+    }
+
+    /// Whether a day is unlocked (shown as a link on the calendar)
+    pub fn is_unlocked(&self, day: u8) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    /// Total stars collected in the year
+    pub fn total_stars(&self) -> u32 {
+        self.days.values().map(|&s| u32::from(s)).sum()
+    }
+}
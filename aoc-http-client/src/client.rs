@@ -1,10 +1,19 @@
 //! AOC HTTP client implementation
 
+use crate::calendar::YearProgress;
 use crate::error::AocError;
-use crate::parser::ResponseParser;
+use crate::fixtures::FixtureMode;
+use crate::leaderboard::{LEADERBOARD_REFRESH_INTERVAL, PrivateLeaderboard};
+use crate::parser::{ResponseParser, check_input};
+use crate::puzzle::{DayAnswers, Puzzle};
+use crate::request::{self, RateLimiter, RetryPolicy};
+use reqwest::Method;
+use reqwest::blocking::{RequestBuilder, Response};
 use reqwest::header::HeaderValue;
-use std::time::Duration;
-use zeroize::Zeroize;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// Result of session verification
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,14 +28,30 @@ pub enum SubmissionResult {
     /// Answer was correct
     Correct,
     /// Answer was incorrect
-    Incorrect,
+    Incorrect {
+        /// Whether AoC said the answer was too high or too low
+        hint: Option<AnswerHint>,
+    },
     /// Problem was already completed
     AlreadyCompleted,
+    /// The submitted part is not the one currently being solved
+    WrongLevel,
     /// Submission was throttled
     Throttled {
         /// Optional wait time before next submission
         wait_time: Option<Duration>,
     },
+    /// The response matched no known outcome; holds the page's main text
+    Unknown(String),
+}
+
+/// Hint attached to an incorrect answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerHint {
+    /// The answer is too high
+    TooHigh,
+    /// The answer is too low
+    TooLow,
 }
 
 /// The main AOC HTTP client
@@ -40,7 +65,7 @@ pub enum SubmissionResult {
 /// use aoc_http_client::AocClient;
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let client = AocClient::new()?;
+/// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
 /// let session = "your_session_cookie";
 ///
 /// // Verify session and get user ID
@@ -60,27 +85,42 @@ pub struct AocClient {
     client: reqwest::blocking::Client,
     base_url: reqwest::Url,
     parser: ResponseParser,
+    leaderboard_interval: Duration,
+    leaderboards: Arc<Mutex<LeaderboardCache>>,
+    rate_limiter: Arc<RateLimiter>,
+    retry: RetryPolicy,
+    fixtures: Option<FixtureMode>,
 }
 
+/// Recently fetched private leaderboards keyed by (year, id), shared between clones
+type LeaderboardCache = HashMap<(u16, u64), (Instant, PrivateLeaderboard)>;
+
 impl AocClient {
     /// Create a new AOC client with rustls-tls configuration and no redirect policy
     ///
     /// The client is configured to not follow redirects by default, which is necessary
     /// for session verification to work correctly.
     ///
+    /// # Arguments
+    ///
+    /// * `contact` - Who runs the tool (e.g. a repository URL and email address),
+    ///   sent in the User-Agent header so AOC can reach the operator
+    ///
     /// # Errors
     ///
-    /// Returns `AocError::ClientInit` if the HTTP client cannot be initialized.
+    /// Returns `AocError::ClientInit` if the HTTP client cannot be initialized or
+    /// the contact is empty.
     ///
     /// # Example
     ///
     /// ```
     /// use aoc_http_client::AocClient;
     ///
-    /// let client = AocClient::new().expect("Failed to create client");
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")
+    ///     .expect("Failed to create client");
     /// ```
-    pub fn new() -> Result<Self, AocError> {
-        Self::builder().build()
+    pub fn new(contact: &str) -> Result<Self, AocError> {
+        Self::builder(contact).build()
     }
 
     /// Create a builder for configuring the AOC client
@@ -91,31 +131,53 @@ impl AocClient {
     /// use aoc_http_client::AocClient;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::builder()
+    /// let client = AocClient::builder("github.com/me/aoc by me@example.com")
     ///     .base_url("http://localhost:1234")?
     ///     .build()?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn builder() -> AocClientBuilder {
-        AocClientBuilder::new()
+    pub fn builder(contact: impl Into<String>) -> AocClientBuilder {
+        AocClientBuilder::new(contact)
     }
 
     /// Create a secure cookie header value from a session string
-    ///
-    /// This function creates a HeaderValue with the sensitive flag set to true
-    /// and zeroizes the temporary string after use.
     fn create_cookie_header(session: &str) -> Result<HeaderValue, AocError> {
-        let mut cookie_string = format!("session={}", session);
-        let header_value = HeaderValue::from_bytes(cookie_string.as_bytes())
-            .map_err(|_| AocError::ClientInit("Invalid session cookie format".to_string()))?;
-
-        // Mark as sensitive and zeroize the temporary string
-        let mut sensitive_header = header_value;
-        sensitive_header.set_sensitive(true);
-        cookie_string.zeroize();
+        request::cookie_header(session)
+    }
 
-        Ok(sensitive_header)
+    /// Send a request, pacing it and retrying transient failures
+    ///
+    /// Every request waits for the shared rate limiter. GET requests are retried
+    /// with exponential backoff on connection errors, timeouts and 5xx responses.
+    /// POST requests are only retried when the connection could not be made, since
+    /// a failed response does not prove the answer was not recorded. 4xx responses
+    /// are never retried.
+    fn send(&self, request: RequestBuilder) -> Result<Response, AocError> {
+        let request = request.build()?;
+        if let Some(FixtureMode::Replay(dir)) = &self.fixtures {
+            return FixtureMode::replay(dir, &request);
+        }
+        let idempotent = request.method() == Method::GET;
+        let mut attempt = 0;
+        loop {
+            let attempt_request = request
+                .try_clone()
+                .expect("requests without streaming bodies can be cloned");
+            self.rate_limiter.wait();
+            let result = self.client.execute(attempt_request);
+            let transient =
+                request::is_transient(idempotent, &result.as_ref().map(Response::status));
+            if !transient || attempt >= self.retry.max_retries {
+                let response = result?;
+                return match &self.fixtures {
+                    Some(FixtureMode::Record(dir)) => FixtureMode::record(dir, &request, response),
+                    _ => Ok(response),
+                };
+            }
+            std::thread::sleep(self.retry.backoff(attempt));
+            attempt += 1;
+        }
     }
 
     /// Verify if a session cookie is valid and retrieve user ID
@@ -140,7 +202,7 @@ impl AocClient {
     /// use aoc_http_client::AocClient;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::new()?;
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
     /// let session = "your_session_cookie";
     ///
     /// let info = client.verify_session(session)?;
@@ -156,17 +218,9 @@ impl AocClient {
         let cookie_header = Self::create_cookie_header(session)?;
 
         // Construct URL using path segments
-        let mut url = self.base_url.clone();
-        url.path_segments_mut()
-            .map_err(|_| AocError::ClientInit("Cannot modify base URL path".to_string()))?
-            .clear()
-            .push("settings");
-
-        let response = self
-            .client
-            .get(url)
-            .header("Cookie", cookie_header)
-            .send()?;
+        let url = request::endpoint(&self.base_url, &["settings"])?;
+
+        let response = self.send(self.client.get(url).header("Cookie", cookie_header))?;
 
         // 2xx success means valid session (settings page loads)
         // 3xx redirect means invalid session (redirecting to homepage)
@@ -198,7 +252,10 @@ impl AocClient {
     /// # Errors
     ///
     /// * `AocError::Request` - Network error
-    /// * `AocError::InvalidStatus` - HTTP error (e.g., 404 if puzzle not available)
+    /// * `AocError::NotYetUnlocked` - The puzzle has not unlocked yet
+    /// * `AocError::SessionExpired` - The session is not logged in
+    /// * `AocError::PuzzleNotFound` - No puzzle exists for the year and day
+    /// * `AocError::InvalidStatus` - Any other HTTP error
     /// * `AocError::Encoding` - Response is not valid UTF-8
     ///
     /// # Example
@@ -207,7 +264,7 @@ impl AocClient {
     /// use aoc_http_client::AocClient;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::new()?;
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
     /// let session = "your_session_cookie";
     ///
     /// let input = client.get_input(2024, 1, session)?;
@@ -219,17 +276,173 @@ impl AocClient {
         let cookie_header = Self::create_cookie_header(session)?;
 
         // Construct URL using path segments
-        let mut url = self.base_url.clone();
-        url.path_segments_mut()
-            .map_err(|_| AocError::ClientInit("Cannot modify base URL path".to_string()))?
-            .clear()
-            .extend(&[&year.to_string(), "day", &day.to_string(), "input"]);
-
-        let response = self
-            .client
-            .get(url)
-            .header("Cookie", cookie_header)
-            .send()?;
+        let url = request::endpoint(
+            &self.base_url,
+            &[&year.to_string(), "day", &day.to_string(), "input"],
+        )?;
+
+        let response = self.send(self.client.get(url).header("Cookie", cookie_header))?;
+        let status = response.status();
+        let body = response.text().map_err(|_| AocError::Encoding);
+
+        // Error pages come with 200 as well as error statuses
+        if let Ok(body) = &body {
+            check_input(body)?;
+        }
+        if !status.is_success() {
+            return Err(AocError::InvalidStatus { status });
+        }
+        body
+    }
+
+    /// Fetch the puzzle description for a specific year and day
+    ///
+    /// Downloads the puzzle page and converts each part's article to Markdown.
+    /// Part 2 and the "Your puzzle answer was" values are only present once the
+    /// session has solved the corresponding parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The AOC year (e.g., 2024)
+    /// * `day` - The day number (1-25)
+    /// * `session` - The session cookie value
+    ///
+    /// # Errors
+    ///
+    /// * `AocError::Request` - Network error
+    /// * `AocError::InvalidStatus` - HTTP error (e.g., 404 if puzzle not available)
+    /// * `AocError::Encoding` - Response is not valid UTF-8
+    /// * `AocError::HtmlParse` - The page contains no puzzle description
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use aoc_http_client::AocClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
+    /// let session = "your_session_cookie";
+    ///
+    /// let puzzle = client.get_puzzle(2023, 5, session)?;
+    /// println!("{}", puzzle.to_markdown());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_puzzle(&self, year: u16, day: u8, session: &str) -> Result<Puzzle, AocError> {
+        let cookie_header = Self::create_cookie_header(session)?;
+
+        // Construct URL using path segments
+        let url = request::endpoint(
+            &self.base_url,
+            &[&year.to_string(), "day", &day.to_string()],
+        )?;
+
+        let response = self.send(self.client.get(url).header("Cookie", cookie_header))?;
+
+        if !response.status().is_success() {
+            return Err(AocError::InvalidStatus {
+                status: response.status(),
+            });
+        }
+
+        let html = response.text().map_err(|_| AocError::Encoding)?;
+        self.parser.parse_puzzle(year, day, &html)
+    }
+
+    /// Fetch the confirmed answers for a range of days in a year
+    ///
+    /// Scrapes the "Your puzzle answer was" values from each day's puzzle page.
+    /// Days without any solved part are omitted. Fetching stops at the first day
+    /// that returns 404, since later days are not unlocked yet either.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The AOC year (e.g., 2024)
+    /// * `days` - The days to fetch (e.g., `1..=25` for a whole year)
+    /// * `session` - The session cookie value
+    ///
+    /// # Errors
+    ///
+    /// Any error from [`get_puzzle`](Self::get_puzzle) other than a 404.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use aoc_http_client::AocClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
+    /// let session = "your_session_cookie";
+    ///
+    /// for day in client.get_answers(2023, 1..=25, session)? {
+    ///     println!("Day {}: {:?}", day.day, day.answers);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_answers(
+        &self,
+        year: u16,
+        days: RangeInclusive<u8>,
+        session: &str,
+    ) -> Result<Vec<DayAnswers>, AocError> {
+        let mut answers = Vec::new();
+        for day in days {
+            let puzzle = match self.get_puzzle(year, day, session) {
+                Ok(puzzle) => puzzle,
+                Err(AocError::InvalidStatus { status })
+                    if status == reqwest::StatusCode::NOT_FOUND =>
+                {
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+            let day_answers = puzzle.answers();
+            if !day_answers.answers.is_empty() {
+                answers.push(day_answers);
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Fetch star progress for a year from its calendar page
+    ///
+    /// Lets callers see which days and parts are already completed without
+    /// submitting anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The AOC year (e.g., 2024)
+    /// * `session` - The session cookie value
+    ///
+    /// # Errors
+    ///
+    /// * `AocError::Request` - Network error
+    /// * `AocError::InvalidStatus` - HTTP error (e.g., 404 for a future year)
+    /// * `AocError::Encoding` - Response is not valid UTF-8
+    /// * `AocError::HtmlParse` - The page has no calendar days
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use aoc_http_client::AocClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
+    /// let session = "your_session_cookie";
+    ///
+    /// let progress = client.get_calendar(2023, session)?;
+    /// println!("{} stars in 2023", progress.total_stars());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_calendar(&self, year: u16, session: &str) -> Result<YearProgress, AocError> {
+        let cookie_header = Self::create_cookie_header(session)?;
+
+        // Construct URL using path segments
+        let url = request::endpoint(&self.base_url, &[&year.to_string()])?;
+
+        let response = self.send(self.client.get(url).header("Cookie", cookie_header))?;
 
         if !response.status().is_success() {
             return Err(AocError::InvalidStatus {
@@ -237,7 +450,89 @@ impl AocClient {
             });
         }
 
-        response.text().map_err(|_| AocError::Encoding)
+        let html = response.text().map_err(|_| AocError::Encoding)?;
+        self.parser.parse_calendar(year, &html)
+    }
+
+    /// Fetch a private leaderboard
+    ///
+    /// AOC asks that private leaderboards are requested at most once every
+    /// [`LEADERBOARD_REFRESH_INTERVAL`]. Within that interval this returns the
+    /// previously fetched leaderboard (shared by all clones of the client) without
+    /// making a request.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The AOC year (e.g., 2024)
+    /// * `id` - The leaderboard ID (the owner's user ID)
+    /// * `session` - The session cookie value of a leaderboard member
+    ///
+    /// # Errors
+    ///
+    /// * `AocError::Request` - Network error
+    /// * `AocError::InvalidStatus` - HTTP error (AOC redirects if the session
+    ///   cannot view the leaderboard)
+    /// * `AocError::Json` - The response is not a valid leaderboard
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use aoc_http_client::AocClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
+    /// let session = "your_session_cookie";
+    ///
+    /// let board = client.get_private_leaderboard(2023, 123456, session)?;
+    /// for member in board.standings() {
+    ///     println!("{} {}", member.local_score, member.display_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_private_leaderboard(
+        &self,
+        year: u16,
+        id: u64,
+        session: &str,
+    ) -> Result<PrivateLeaderboard, AocError> {
+        let key = (year, id);
+        if let Some((fetched_at, board)) = self.leaderboards.lock().unwrap().get(&key)
+            && fetched_at.elapsed() < self.leaderboard_interval
+        {
+            return Ok(board.clone());
+        }
+
+        let cookie_header = Self::create_cookie_header(session)?;
+
+        // Construct URL using path segments
+        let url = request::endpoint(
+            &self.base_url,
+            &[
+                &year.to_string(),
+                "leaderboard",
+                "private",
+                "view",
+                &format!("{}.json", id),
+            ],
+        )?;
+
+        let response = self.send(self.client.get(url).header("Cookie", cookie_header))?;
+
+        if !response.status().is_success() {
+            return Err(AocError::InvalidStatus {
+                status: response.status(),
+            });
+        }
+
+        let body = response.text().map_err(|_| AocError::Encoding)?;
+        let board: PrivateLeaderboard = serde_json::from_str(&body)?;
+
+        self.leaderboards
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), board.clone()));
+        Ok(board)
     }
 
     /// Submit an answer for a puzzle part
@@ -256,14 +551,17 @@ impl AocClient {
     ///
     /// A `SubmissionResult` indicating the outcome:
     /// * `Correct` - Answer was correct
-    /// * `Incorrect` - Answer was incorrect
+    /// * `Incorrect` - Answer was incorrect (with a too high/too low hint if given)
     /// * `AlreadyCompleted` - Problem was already solved
+    /// * `WrongLevel` - The part is not the one currently being solved
     /// * `Throttled` - Submission was rate-limited (includes optional wait time)
+    /// * `Unknown` - The response was not recognised (includes the page text)
     ///
     /// # Errors
     ///
     /// * `AocError::Request` - Network error
     /// * `AocError::InvalidStatus` - HTTP error
+    /// * `AocError::SessionExpired` - AoC answered with its login page
     /// * `AocError::Encoding` - Response is not valid UTF-8
     /// * `AocError::HtmlParse` - Failed to parse HTML response
     ///
@@ -273,17 +571,19 @@ impl AocClient {
     /// use aoc_http_client::{AocClient, SubmissionResult};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::new()?;
+    /// let client = AocClient::new("github.com/me/aoc by me@example.com")?;
     /// let session = "your_session_cookie";
     ///
     /// let result = client.submit_answer(2024, 1, 1, "42", session)?;
     /// match result {
     ///     SubmissionResult::Correct => println!("Correct!"),
-    ///     SubmissionResult::Incorrect => println!("Try again"),
+    ///     SubmissionResult::Incorrect { .. } => println!("Try again"),
     ///     SubmissionResult::AlreadyCompleted => println!("Already done"),
+    ///     SubmissionResult::WrongLevel => println!("Wrong part"),
     ///     SubmissionResult::Throttled { wait_time } => {
     ///         println!("Wait: {:?}", wait_time);
     ///     }
+    ///     SubmissionResult::Unknown(text) => println!("Unrecognised: {}", text),
     /// }
     /// # Ok(())
     /// # }
@@ -299,20 +599,19 @@ impl AocClient {
         let cookie_header = Self::create_cookie_header(session)?;
 
         // Construct URL using path segments
-        let mut url = self.base_url.clone();
-        url.path_segments_mut()
-            .map_err(|_| AocError::ClientInit("Cannot modify base URL path".to_string()))?
-            .clear()
-            .extend(&[&year.to_string(), "day", &day.to_string(), "answer"]);
+        let url = request::endpoint(
+            &self.base_url,
+            &[&year.to_string(), "day", &day.to_string(), "answer"],
+        )?;
 
         let form = [("level", part.to_string()), ("answer", answer.to_string())];
 
-        let response = self
-            .client
-            .post(url)
-            .header("Cookie", cookie_header)
-            .form(&form)
-            .send()?;
+        let response = self.send(
+            self.client
+                .post(url)
+                .header("Cookie", cookie_header)
+                .form(&form),
+        )?;
 
         if !response.status().is_success() {
             return Err(AocError::InvalidStatus {
@@ -327,8 +626,9 @@ impl AocClient {
 
 /// Builder for configuring an AOC HTTP client
 ///
-/// This builder allows customization of the base URL and HTTP client configuration
-/// while ensuring the redirect policy is always set correctly for session verification.
+/// This builder allows customization of the base URL, HTTP client configuration,
+/// request pacing and retries while ensuring the redirect policy and User-Agent are
+/// always set correctly.
 ///
 /// # Example
 ///
@@ -338,15 +638,15 @@ impl AocClient {
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// // Default client
-/// let client = AocClient::builder().build()?;
+/// let client = AocClient::builder("github.com/me/aoc by me@example.com").build()?;
 ///
 /// // Custom base URL for testing
-/// let client = AocClient::builder()
+/// let client = AocClient::builder("github.com/me/aoc by me@example.com")
 ///     .base_url("http://localhost:1234")?
 ///     .build()?;
 ///
 /// // Custom timeout
-/// let client = AocClient::builder()
+/// let client = AocClient::builder("github.com/me/aoc by me@example.com")
 ///     .client_builder(
 ///         reqwest::blocking::Client::builder()
 ///             .timeout(Duration::from_secs(30))
@@ -354,7 +654,7 @@ impl AocClient {
 ///     .build()?;
 ///
 /// // Combine custom base URL and timeout
-/// let client = AocClient::builder()
+/// let client = AocClient::builder("github.com/me/aoc by me@example.com")
 ///     .base_url("http://localhost:1234")?
 ///     .client_builder(
 ///         reqwest::blocking::Client::builder()
@@ -366,16 +666,29 @@ impl AocClient {
 /// ```
 #[derive(Debug)]
 pub struct AocClientBuilder {
+    contact: String,
     base_url: Option<reqwest::Url>,
     client_builder: Option<reqwest::blocking::ClientBuilder>,
+    leaderboard_interval: Duration,
+    min_request_interval: Duration,
+    retry: RetryPolicy,
+    fixtures: Option<FixtureMode>,
 }
 
 impl AocClientBuilder {
     /// Create a new builder with default settings
-    pub fn new() -> Self {
+    ///
+    /// `contact` identifies who runs the tool and is sent in the User-Agent header
+    /// as `aoc-http-client/{version} ({contact})`.
+    pub fn new(contact: impl Into<String>) -> Self {
         Self {
+            contact: contact.into(),
             base_url: None,
             client_builder: None,
+            leaderboard_interval: LEADERBOARD_REFRESH_INTERVAL,
+            min_request_interval: Duration::ZERO,
+            retry: RetryPolicy::default(),
+            fixtures: None,
         }
     }
 
@@ -398,7 +711,7 @@ impl AocClientBuilder {
     /// use aoc_http_client::AocClient;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::builder()
+    /// let client = AocClient::builder("github.com/me/aoc by me@example.com")
     ///     .base_url("http://localhost:1234")?
     ///     .build()?;
     /// # Ok(())
@@ -426,7 +739,7 @@ impl AocClientBuilder {
     /// use std::time::Duration;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::builder()
+    /// let client = AocClient::builder("github.com/me/aoc by me@example.com")
     ///     .client_builder(
     ///         reqwest::blocking::Client::builder()
     ///             .timeout(Duration::from_secs(30))
@@ -441,11 +754,73 @@ impl AocClientBuilder {
         self
     }
 
+    /// Set how long fetched private leaderboards are reused before refetching
+    ///
+    /// Defaults to [`LEADERBOARD_REFRESH_INTERVAL`], the minimum AOC allows.
+    /// Shorter intervals are only appropriate against mock servers.
+    pub fn leaderboard_refresh_interval(mut self, interval: Duration) -> Self {
+        self.leaderboard_interval = interval;
+        self
+    }
+
+    /// Set the minimum time between the start of two requests
+    ///
+    /// The limit is shared by all clones of the built client, so it holds across
+    /// threads. Defaults to no limit.
+    pub fn min_request_interval(mut self, interval: Duration) -> Self {
+        self.min_request_interval = interval;
+        self
+    }
+
+    /// Set how many times a transient failure is retried (default [`DEFAULT_MAX_RETRIES`])
+    ///
+    /// [`DEFAULT_MAX_RETRIES`]: crate::DEFAULT_MAX_RETRIES
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry.max_retries = retries;
+        self
+    }
+
+    /// Set the delay before the first retry (default [`DEFAULT_INITIAL_BACKOFF`])
+    ///
+    /// Each further retry waits twice as long as the previous one.
+    ///
+    /// [`DEFAULT_INITIAL_BACKOFF`]: crate::DEFAULT_INITIAL_BACKOFF
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.retry.initial_backoff = backoff;
+        self
+    }
+
+    /// Record exchanges to, or replay them from, fixture files
+    ///
+    /// In [`FixtureMode::Record`] every final response is saved with session
+    /// cookies redacted. In [`FixtureMode::Replay`] no request reaches the network:
+    /// each one is answered from its saved exchange, or fails with
+    /// `AocError::Fixture` if there is none.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use aoc_http_client::{AocClient, FixtureMode};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = AocClient::builder("github.com/me/aoc by me@example.com")
+    ///     .fixtures(FixtureMode::Replay("tests/fixtures/http".into()))
+    ///     .build()?;
+    /// let input = client.get_input(2023, 1, "any session")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fixtures(mut self, mode: FixtureMode) -> Self {
+        self.fixtures = Some(mode);
+        self
+    }
+
     /// Build the AOC client with the configured settings
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The contact is empty or cannot be sent in a header
     /// - The HTTP client cannot be initialized
     /// - The default base URL cannot be parsed (should never happen)
     ///
@@ -455,16 +830,15 @@ impl AocClientBuilder {
     /// use aoc_http_client::AocClient;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = AocClient::builder().build()?;
+    /// let client = AocClient::builder("github.com/me/aoc by me@example.com").build()?;
     /// # Ok(())
     /// # }
     /// ```
     pub fn build(self) -> Result<AocClient, AocError> {
+        let user_agent = request::user_agent(&self.contact)?;
+
         // Use provided base URL or default to adventofcode.com
-        let base_url = self.base_url.unwrap_or_else(|| {
-            reqwest::Url::parse("https://adventofcode.com")
-                .expect("Default base URL should always be valid")
-        });
+        let base_url = self.base_url.unwrap_or_else(request::default_base_url);
 
         // Use provided client builder or create default with rustls-tls
         let builder = self
@@ -474,6 +848,7 @@ impl AocClientBuilder {
         // Always override redirect policy to none for session verification
         let client = builder
             .redirect(reqwest::redirect::Policy::none())
+            .user_agent(user_agent)
             .build()
             .map_err(|e| AocError::ClientInit(e.to_string()))?;
 
@@ -481,21 +856,22 @@ impl AocClientBuilder {
             client,
             base_url,
             parser: ResponseParser::new(),
+            leaderboard_interval: self.leaderboard_interval,
+            leaderboards: Arc::default(),
+            rate_limiter: Arc::new(RateLimiter::new(self.min_request_interval)),
+            retry: self.retry,
+            fixtures: self.fixtures,
         })
     }
 }
 
-impl Default for AocClientBuilder {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    const TEST_CONTACT: &str = "aoc-http-client tests";
+
     // **Feature: aoc-http-client, Property 11: Base URL configuration**
     // **Validates: Requirements 10.3**
     proptest! {
@@ -511,7 +887,7 @@ mod tests {
             let base_url = format!("{}://{}:{}", scheme, host, port);
 
             // Build client with custom base URL
-            let client = AocClient::builder()
+            let client = AocClient::builder(TEST_CONTACT)
                 .base_url(&base_url)
                 .unwrap()
                 .build()
@@ -532,7 +908,7 @@ mod tests {
         #[test]
         fn prop_default_base_url(_dummy in 0u8..10u8) {
             // Create client without specifying base URL
-            let client = AocClient::builder().build().unwrap();
+            let client = AocClient::builder(TEST_CONTACT).build().unwrap();
 
             // Verify default base URL is used
             prop_assert_eq!(client.base_url.as_str(), "https://adventofcode.com/");
@@ -554,7 +930,7 @@ mod tests {
                 .use_rustls_tls();
 
             // Build client with custom ClientBuilder
-            let result = AocClient::builder()
+            let result = AocClient::builder(TEST_CONTACT)
                 .client_builder(custom_builder)
                 .build();
 
@@ -586,7 +962,7 @@ mod tests {
             .create();
 
         // Build client with default settings (tests that redirect policy is enforced by default)
-        let client = AocClient::builder()
+        let client = AocClient::builder(TEST_CONTACT)
             .base_url(server.url())
             .unwrap()
             .build()
@@ -606,7 +982,7 @@ mod tests {
 
     #[test]
     fn test_invalid_base_url() {
-        let result = AocClient::builder().base_url("not a valid url");
+        let result = AocClient::builder(TEST_CONTACT).base_url("not a valid url");
 
         assert!(result.is_err());
     }
@@ -632,7 +1008,7 @@ mod tests {
                 .create();
 
             // Build client with mock server URL
-            let client = AocClient::builder()
+            let client = AocClient::builder(TEST_CONTACT)
                 .base_url(server.url())
                 .unwrap()
                 .build()
@@ -676,9 +1052,11 @@ mod tests {
             };
 
             // Build client with mock server URL
-            let client = AocClient::builder()
+            // Retries of 5xx responses are covered separately
+            let client = AocClient::builder(TEST_CONTACT)
                 .base_url(server.url())
                 .unwrap()
+                .max_retries(0)
                 .build()
                 .unwrap();
 
@@ -718,7 +1096,7 @@ mod tests {
                 .create();
 
             // Build client with mock server URL
-            let client = AocClient::builder()
+            let client = AocClient::builder(TEST_CONTACT)
                 .base_url(server.url())
                 .unwrap()
                 .build()
@@ -747,6 +1125,396 @@ mod tests {
         }
     }
 
+    // **Feature: aoc-http-client, Property 17: Puzzle URL construction**
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(10))]
+
+        #[test]
+        fn prop_puzzle_url_construction(
+            year in 2015u16..2030u16,
+            day in 1u8..=25u8,
+            session in "[a-f0-9]{32,128}",
+        ) {
+            let mut server = mockito::Server::new();
+
+            let expected_path = format!("/{}/day/{}", year, day);
+            let body = format!(
+                r#"<html><body><main><article class="day-desc"><h2>--- Day {}: Test ---</h2><p>Body</p></article></main></body></html>"#,
+                day
+            );
+            let mock = server.mock("GET", expected_path.as_str())
+                .with_status(200)
+                .with_body(&body)
+                .expect(1)
+                .create();
+
+            let client = AocClient::builder(TEST_CONTACT)
+                .base_url(server.url())
+                .unwrap()
+                .build()
+                .unwrap();
+
+            let puzzle = client.get_puzzle(year, day, &session);
+            mock.assert();
+
+            let puzzle = puzzle.unwrap();
+            prop_assert_eq!((puzzle.year, puzzle.day), (year, day));
+            prop_assert_eq!(puzzle.title, "Test");
+            prop_assert_eq!(puzzle.parts.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_get_answers_stops_at_locked_day() {
+        let mut server = mockito::Server::new();
+        let solved = server
+            .mock("GET", "/2023/day/1")
+            .with_status(200)
+            .with_body(
+                r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>One</p></article>
+<p>Your puzzle answer was <code>55208</code>.</p>
+<article class="day-desc"><h2>--- Part Two ---</h2><p>Two</p></article>
+<p>Your puzzle answer was <code>54578</code>.</p>
+</main></body></html>"#,
+            )
+            .create();
+        let unsolved = server
+            .mock("GET", "/2023/day/2")
+            .with_status(200)
+            .with_body(
+                r#"<html><body><main><article class="day-desc"><h2>--- Day 2: Cube Conundrum ---</h2></article></main></body></html>"#,
+            )
+            .create();
+        let locked = server
+            .mock("GET", "/2023/day/3")
+            .with_status(404)
+            .expect(1)
+            .create();
+        let after_locked = server.mock("GET", "/2023/day/4").expect(0).create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+        let answers = client.get_answers(2023, 1..=4, "session").unwrap();
+
+        assert_eq!(
+            answers,
+            vec![DayAnswers {
+                year: 2023,
+                day: 1,
+                answers: vec!["55208".to_string(), "54578".to_string()],
+            }]
+        );
+        solved.assert();
+        unsolved.assert();
+        locked.assert();
+        after_locked.assert();
+    }
+
+    #[test]
+    fn test_get_calendar() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023")
+            .with_status(200)
+            .with_body(
+                r#"<html><body><main><pre class="calendar"><a href="/2023/day/1" class="calendar-day1 calendar-complete">1</a></pre></main></body></html>"#,
+            )
+            .expect(1)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+        let progress = client.get_calendar(2023, "session").unwrap();
+
+        assert_eq!(progress.year, 2023);
+        assert_eq!(progress.stars(1), 1);
+        mock.assert();
+    }
+
+    const LEADERBOARD_JSON: &str = r#"{"event":"2023","owner_id":7,"members":{"7":{"id":7,"name":"owner","stars":2,"local_score":4,"global_score":0,"last_star_ts":1701407400,"completion_day_level":{"1":{"1":{"get_star_ts":1701407100,"star_index":1},"2":{"get_star_ts":1701407400,"star_index":2}}}}}}"#;
+
+    #[test]
+    fn test_private_leaderboard_is_cached() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023/leaderboard/private/view/7.json")
+            .match_header("cookie", "session=test_session")
+            .with_status(200)
+            .with_body(LEADERBOARD_JSON)
+            .expect(1)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let board = client
+            .get_private_leaderboard(2023, 7, "test_session")
+            .unwrap();
+        assert_eq!(board.members[&7].get_star_ts(1, 2), Some(1701407400));
+
+        // Within the refresh interval, clones reuse the cached leaderboard
+        let again = client
+            .clone()
+            .get_private_leaderboard(2023, 7, "test_session")
+            .unwrap();
+        assert_eq!(again, board);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_private_leaderboard_refetches_after_interval() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023/leaderboard/private/view/7.json")
+            .with_status(200)
+            .with_body(LEADERBOARD_JSON)
+            .expect(2)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .leaderboard_refresh_interval(Duration::ZERO)
+            .build()
+            .unwrap();
+
+        client.get_private_leaderboard(2023, 7, "s").unwrap();
+        client.get_private_leaderboard(2023, 7, "s").unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_private_leaderboard_invalid_json() {
+        let mut server = mockito::Server::new();
+        let _mock = server
+            .mock("GET", "/2023/leaderboard/private/view/7.json")
+            .with_status(200)
+            .with_body("<html>not json</html>")
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client.get_private_leaderboard(2023, 7, "s"),
+            Err(AocError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_user_agent_includes_contact() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023/day/1/input")
+            .match_header(
+                "user-agent",
+                mockito::Matcher::Regex(r"^aoc-http-client/\S+ \(aoc-http-client tests\)$".into()),
+            )
+            .with_status(200)
+            .with_body("input")
+            .expect(1)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_input(2023, 1, "s").unwrap(), "input");
+        mock.assert();
+    }
+
+    #[test]
+    fn test_empty_contact_is_rejected() {
+        assert!(matches!(AocClient::new("  "), Err(AocError::ClientInit(_))));
+    }
+
+    #[test]
+    fn test_server_errors_are_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023/day/1/input")
+            .with_status(503)
+            .expect(3)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .max_retries(2)
+            .initial_backoff(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client.get_input(2023, 1, "s"),
+            Err(AocError::InvalidStatus { status }) if status == 503
+        ));
+        mock.assert();
+    }
+
+    #[test]
+    fn test_client_errors_are_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023/day/1/input")
+            .with_status(404)
+            .expect(1)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .initial_backoff(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        assert!(client.get_input(2023, 1, "s").is_err());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_get_input_rejects_error_pages() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/2023/day/1/input")
+            .with_status(200)
+            .with_body("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")
+            .create();
+        server
+            .mock("GET", "/2023/day/2/input")
+            .with_status(404)
+            .with_body("Please don't repeatedly request this endpoint before it unlocks!\n")
+            .create();
+        server
+            .mock("GET", "/2030/day/1/input")
+            .with_status(404)
+            .with_body("404 Not Found\n")
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client.get_input(2023, 1, "s"),
+            Err(AocError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.get_input(2023, 2, "s"),
+            Err(AocError::NotYetUnlocked)
+        ));
+        assert!(matches!(
+            client.get_input(2030, 1, "s"),
+            Err(AocError::PuzzleNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_auth_failures_are_classified() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/2023/day/1/answer")
+            .with_status(200)
+            .with_body("<html><body><main><p>To play, please identify yourself via one of these services:</p></main></body></html>")
+            .create();
+        server
+            .mock("GET", "/2023/day/1/input")
+            .with_status(403)
+            .create();
+        server
+            .mock("GET", "/2023/day/2/input")
+            .with_status(500)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let err = client.submit_answer(2023, 1, 1, "42", "s").unwrap_err();
+        assert!(matches!(err, AocError::SessionExpired));
+        assert!(err.is_auth_failure());
+        assert!(
+            client
+                .get_input(2023, 1, "s")
+                .unwrap_err()
+                .is_auth_failure()
+        );
+        assert!(
+            !client
+                .get_input(2023, 2, "s")
+                .unwrap_err()
+                .is_auth_failure()
+        );
+    }
+
+    #[test]
+    fn test_submission_server_errors_are_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/2023/day/1/answer")
+            .with_status(502)
+            .expect(1)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .initial_backoff(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        assert!(client.submit_answer(2023, 1, 1, "42", "s").is_err());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_min_request_interval_is_shared_by_clones() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/2023/day/1/input")
+            .with_status(200)
+            .with_body("input")
+            .expect(3)
+            .create();
+
+        let client = AocClient::builder(TEST_CONTACT)
+            .base_url(server.url())
+            .unwrap()
+            .min_request_interval(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let clone = client.clone();
+
+        let start = Instant::now();
+        let handle = std::thread::spawn(move || clone.get_input(2023, 1, "s").unwrap());
+        client.get_input(2023, 1, "s").unwrap();
+        client.get_input(2023, 1, "s").unwrap();
+        handle.join().unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        mock.assert();
+    }
+
     // **Feature: aoc-http-client, Property 4: Submission request construction**
     // **Validates: Requirements 3.1, 3.2**
     proptest! {
@@ -779,7 +1547,7 @@ mod tests {
                 .create();
 
             // Build client with mock server URL
-            let client = AocClient::builder()
+            let client = AocClient::builder(TEST_CONTACT)
                 .base_url(server.url())
                 .unwrap()
                 .build()
@@ -836,9 +1604,11 @@ mod tests {
                 .create();
 
             // Build client with mock server URL
-            let client = AocClient::builder()
+            // Retries of 5xx responses are covered separately
+            let client = AocClient::builder(TEST_CONTACT)
                 .base_url(server.url())
                 .unwrap()
+                .max_retries(0)
                 .build()
                 .unwrap();
 
@@ -24,11 +24,48 @@ pub enum AocError {
     #[error("Failed to parse HTML response")]
     HtmlParse,
 
+    /// Failed to parse JSON response
+    #[error("Failed to parse JSON response: {0}")]
+    Json(#[from] serde_json::Error),
+
     /// Failed to parse duration string
     #[error("Failed to parse duration: {0}")]
     DurationParse(String),
 
+    /// The puzzle has not unlocked yet
+    #[error("Puzzle has not unlocked yet")]
+    NotYetUnlocked,
+
+    /// The session cookie is missing, invalid or expired
+    #[error("Session expired or not logged in")]
+    SessionExpired,
+
+    /// No puzzle exists for the requested year and day
+    #[error("Puzzle not found")]
+    PuzzleNotFound,
+
     /// Client initialization failed
     #[error("Client initialization failed: {0}")]
     ClientInit(String),
+
+    /// A recorded HTTP fixture is missing or cannot be read or written
+    #[error("HTTP fixture error: {0}")]
+    Fixture(String),
+}
+
+impl AocError {
+    /// Whether the error means the session cookie was rejected
+    ///
+    /// True for [`AocError::SessionExpired`] and for 401/403 responses. Callers
+    /// can use this to ask for a fresh session and retry instead of failing.
+    pub fn is_auth_failure(&self) -> bool {
+        match self {
+            AocError::SessionExpired => true,
+            AocError::InvalidStatus { status } => {
+                *status == reqwest::StatusCode::UNAUTHORIZED
+                    || *status == reqwest::StatusCode::FORBIDDEN
+            }
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,238 @@
+//! In-memory [`AocApi`] for tests, enabled by the `fake` feature
+
+use crate::api::AocApi;
+use crate::calendar::YearProgress;
+use crate::client::{SessionInfo, SubmissionResult};
+use crate::error::AocError;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Queued responses, consumed front to back
+type Queue<K, T> = Mutex<HashMap<K, VecDeque<T>>>;
+
+/// A call received by a [`FakeAocApi`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FakeCall {
+    VerifySession,
+    GetInput {
+        year: u16,
+        day: u8,
+    },
+    SubmitAnswer {
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: String,
+    },
+    GetCalendar {
+        year: u16,
+    },
+}
+
+/// Scripted, in-memory stand-in for [`AocClient`](crate::AocClient)
+///
+/// Inputs and calendars are returned on every request. Queued input errors are
+/// returned, in order, before the input, and each queued submission response is
+/// returned once. Unscripted inputs fail with `AocError::PuzzleNotFound` and
+/// unscripted submissions return `SubmissionResult::Unknown`. Every call is
+/// recorded and available from [`calls`](Self::calls). The session is ignored.
+///
+/// # Example
+///
+/// ```
+/// use aoc_http_client::{AocApi, FakeAocApi, SubmissionResult};
+///
+/// let api = FakeAocApi::new()
+///     .with_input(2023, 1, "1abc2")
+///     .with_submission(2023, 1, 1, Ok(SubmissionResult::Correct));
+///
+/// assert_eq!(api.get_input(2023, 1, "any").unwrap(), "1abc2");
+/// assert_eq!(
+///     api.submit_answer(2023, 1, 1, "12", "any").unwrap(),
+///     SubmissionResult::Correct
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct FakeAocApi {
+    user_id: Option<u64>,
+    inputs: HashMap<(u16, u8), String>,
+    input_errors: Queue<(u16, u8), AocError>,
+    submissions: Queue<(u16, u8, u8), Result<SubmissionResult, AocError>>,
+    calendars: HashMap<u16, YearProgress>,
+    calls: Mutex<Vec<FakeCall>>,
+}
+
+impl FakeAocApi {
+    /// Create a fake with nothing scripted and no valid session
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept every session as belonging to `user_id`
+    pub fn with_user_id(mut self, user_id: u64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Serve `input` for a year/day
+    pub fn with_input(mut self, year: u16, day: u8, input: impl Into<String>) -> Self {
+        self.inputs.insert((year, day), input.into());
+        self
+    }
+
+    /// Fail the next input request for a year/day with `error`
+    pub fn with_input_error(mut self, year: u16, day: u8, error: AocError) -> Self {
+        self.input_errors
+            .get_mut()
+            .unwrap()
+            .entry((year, day))
+            .or_default()
+            .push_back(error);
+        self
+    }
+
+    /// Queue the response to a submission for a part
+    pub fn with_submission(
+        mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        response: Result<SubmissionResult, AocError>,
+    ) -> Self {
+        self.submissions
+            .get_mut()
+            .unwrap()
+            .entry((year, day, part))
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// Serve calendar progress for a year
+    pub fn with_calendar(mut self, progress: YearProgress) -> Self {
+        self.calendars.insert(progress.year, progress);
+        self
+    }
+
+    /// The calls received so far, in order
+    pub fn calls(&self) -> Vec<FakeCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: FakeCall) {
+        self.calls.lock().unwrap().push(call);
+    }
+}
+
+impl AocApi for FakeAocApi {
+    fn verify_session(&self, _session: &str) -> Result<SessionInfo, AocError> {
+        self.record(FakeCall::VerifySession);
+        Ok(SessionInfo {
+            user_id: self.user_id,
+        })
+    }
+
+    fn get_input(&self, year: u16, day: u8, _session: &str) -> Result<String, AocError> {
+        self.record(FakeCall::GetInput { year, day });
+        let queued = self
+            .input_errors
+            .lock()
+            .unwrap()
+            .get_mut(&(year, day))
+            .and_then(VecDeque::pop_front);
+        if let Some(error) = queued {
+            return Err(error);
+        }
+        self.inputs
+            .get(&(year, day))
+            .cloned()
+            .ok_or(AocError::PuzzleNotFound)
+    }
+
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        _session: &str,
+    ) -> Result<SubmissionResult, AocError> {
+        self.record(FakeCall::SubmitAnswer {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+        });
+        self.submissions
+            .lock()
+            .unwrap()
+            .get_mut(&(year, day, part))
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_else(|| {
+                Ok(SubmissionResult::Unknown(
+                    "no scripted submission response".to_string(),
+                ))
+            })
+    }
+
+    fn get_calendar(&self, year: u16, _session: &str) -> Result<YearProgress, AocError> {
+        self.record(FakeCall::GetCalendar { year });
+        Ok(self.calendars.get(&year).cloned().unwrap_or(YearProgress {
+            year,
+            ..YearProgress::default()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scripted_responses_and_calls() {
+        let api = FakeAocApi::new()
+            .with_user_id(7)
+            .with_input(2023, 1, "input")
+            .with_input_error(2023, 1, AocError::NotYetUnlocked)
+            .with_submission(
+                2023,
+                1,
+                1,
+                Ok(SubmissionResult::Throttled { wait_time: None }),
+            )
+            .with_submission(2023, 1, 1, Ok(SubmissionResult::Correct));
+
+        assert_eq!(api.verify_session("s").unwrap().user_id, Some(7));
+        assert!(matches!(
+            api.get_input(2023, 1, "s"),
+            Err(AocError::NotYetUnlocked)
+        ));
+        assert_eq!(api.get_input(2023, 1, "s").unwrap(), "input");
+        assert!(matches!(
+            api.get_input(2023, 2, "s"),
+            Err(AocError::PuzzleNotFound)
+        ));
+        assert_eq!(
+            api.submit_answer(2023, 1, 1, "42", "s").unwrap(),
+            SubmissionResult::Throttled { wait_time: None }
+        );
+        assert_eq!(
+            api.submit_answer(2023, 1, 1, "42", "s").unwrap(),
+            SubmissionResult::Correct
+        );
+        assert!(matches!(
+            api.submit_answer(2023, 1, 1, "42", "s").unwrap(),
+            SubmissionResult::Unknown(_)
+        ));
+        assert_eq!(api.calls().len(), 7);
+        assert_eq!(
+            api.calls()[4],
+            FakeCall::SubmitAnswer {
+                year: 2023,
+                day: 1,
+                part: 1,
+                answer: "42".to_string()
+            }
+        );
+    }
+}
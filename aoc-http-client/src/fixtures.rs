@@ -0,0 +1,312 @@
+//! Record/replay of HTTP exchanges for offline tests
+//!
+//! Each request/response pair is stored as one JSON file named after the request
+//! (see [`fixture_name`]). Session cookies are never written: every cookie value
+//! in the `Cookie` and `Set-Cookie` headers is replaced by [`REDACTED`], as is any
+//! occurrence of the session value in other headers and the recorded bodies.
+
+use crate::error::AocError;
+use reqwest::blocking::{Request, Response};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Placeholder written in place of session cookies
+pub const REDACTED: &str = "<redacted>";
+
+/// How an [`AocClient`](crate::AocClient) uses recorded HTTP fixtures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Send requests to the server and save each exchange in the directory
+    Record(PathBuf),
+    /// Answer requests from the exchanges saved in the directory, without network
+    Replay(PathBuf),
+}
+
+/// A saved request/response pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Exchange {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+/// File name of the fixture for a request
+///
+/// Built from the method, path, query and form body, with every character other
+/// than ASCII letters and digits replaced by `_`, e.g.
+/// `get_2023_day_1_input.json` or `post_2023_day_1_answer_level_1_answer_42.json`.
+pub fn fixture_name(method: &str, path_and_query: &str, body: Option<&str>) -> String {
+    let key = format!("{} {} {}", method, path_and_query, body.unwrap_or_default());
+    let mut name = String::with_capacity(key.len());
+    for c in key.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    format!("{}.json", name.trim_matches('_'))
+}
+
+impl FixtureMode {
+    /// Serve a request from its saved exchange
+    pub(crate) fn replay(dir: &Path, request: &Request) -> Result<Response, AocError> {
+        let path = dir.join(request_fixture_name(request));
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| AocError::Fixture(format!("cannot read {}: {}", path.display(), e)))?;
+        let exchange: Exchange = serde_json::from_str(&contents)?;
+        to_response(exchange.response)
+    }
+
+    /// Save an exchange, returning an equivalent response for the caller
+    pub(crate) fn record(
+        dir: &Path,
+        request: &Request,
+        response: Response,
+    ) -> Result<Response, AocError> {
+        let session = request
+            .headers()
+            .get(reqwest::header::COOKIE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(';').find_map(|c| c.trim().strip_prefix("session=")))
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        let redact = |text: &str| match &session {
+            Some(session) => text.replace(session.as_str(), REDACTED),
+            None => text.to_string(),
+        };
+
+        let recorded_request = RecordedRequest {
+            method: request.method().to_string(),
+            path: path_and_query(request),
+            body: request_body(request).map(|b| redact(&b)),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    let value = String::from_utf8_lossy(value.as_bytes());
+                    let value = if name == reqwest::header::COOKIE {
+                        redact_cookie(&value)
+                    } else {
+                        redact(&value)
+                    };
+                    (name.to_string(), value)
+                })
+                .collect(),
+        };
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = String::from_utf8_lossy(value.as_bytes());
+                let value = if name == reqwest::header::SET_COOKIE {
+                    redact_set_cookie(&value)
+                } else {
+                    redact(&value)
+                };
+                (name.to_string(), value)
+            })
+            .collect();
+        let body = redact(&String::from_utf8_lossy(&response.bytes()?));
+        let exchange = Exchange {
+            request: recorded_request,
+            response: RecordedResponse {
+                status,
+                headers,
+                body,
+            },
+        };
+
+        fs::create_dir_all(dir)
+            .map_err(|e| AocError::Fixture(format!("cannot create {}: {}", dir.display(), e)))?;
+        let path = dir.join(request_fixture_name(request));
+        fs::write(&path, serde_json::to_string_pretty(&exchange)?)
+            .map_err(|e| AocError::Fixture(format!("cannot write {}: {}", path.display(), e)))?;
+        to_response(exchange.response)
+    }
+}
+
+/// Replace the value of every `name=value` pair in a `Cookie` header
+fn redact_cookie(header: &str) -> String {
+    header
+        .split(';')
+        .map(|pair| match pair.trim().split_once('=') {
+            Some((name, _)) => format!("{}={}", name, REDACTED),
+            None => pair.trim().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Replace the cookie value of a `Set-Cookie` header, keeping its attributes
+fn redact_set_cookie(header: &str) -> String {
+    match header.split_once(';') {
+        Some((cookie, attributes)) => format!("{};{}", redact_cookie(cookie), attributes),
+        None => redact_cookie(header),
+    }
+}
+
+fn path_and_query(request: &Request) -> String {
+    let url = request.url();
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
+
+fn request_body(request: &Request) -> Option<String> {
+    let bytes = request.body()?.as_bytes()?;
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
+
+fn request_fixture_name(request: &Request) -> String {
+    fixture_name(
+        request.method().as_str(),
+        &path_and_query(request),
+        request_body(request).as_deref(),
+    )
+}
+
+fn to_response(recorded: RecordedResponse) -> Result<Response, AocError> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name, value);
+    }
+    let response = builder
+        .body(recorded.body)
+        .map_err(|e| AocError::Fixture(format!("invalid recorded response: {}", e)))?;
+    Ok(Response::from(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocClient, SubmissionResult};
+
+    const TEST_CONTACT: &str = "aoc-http-client tests";
+    const SESSION: &str = "53616c7465645f5fsecret";
+
+    fn client(base_url: &str, mode: FixtureMode) -> AocClient {
+        AocClient::builder(TEST_CONTACT)
+            .base_url(base_url)
+            .unwrap()
+            .max_retries(0)
+            .fixtures(mode)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fixture_name() {
+        assert_eq!(
+            fixture_name("GET", "/2023/day/1/input", None),
+            "get_2023_day_1_input.json"
+        );
+        assert_eq!(
+            fixture_name("POST", "/2023/day/1/answer", Some("level=1&answer=42")),
+            "post_2023_day_1_answer_level_1_answer_42.json"
+        );
+    }
+
+    #[test]
+    fn test_cookie_headers_are_redacted_structurally() {
+        assert_eq!(
+            redact_cookie("session=53616c74; _ga=GA1.2.3"),
+            "session=<redacted>; _ga=<redacted>"
+        );
+        assert_eq!(
+            redact_set_cookie("session=53616c74; Expires=Fri, 13 Dec 2033 18:00:00 GMT; Secure"),
+            "session=<redacted>; Expires=Fri, 13 Dec 2033 18:00:00 GMT; Secure"
+        );
+        assert_eq!(redact_set_cookie("session=abc"), "session=<redacted>");
+    }
+
+    #[test]
+    fn test_record_then_replay_offline() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/2023/day/1/input")
+            .with_status(200)
+            .with_header("set-cookie", "session=rotated; Path=/; Secure; HttpOnly")
+            .with_body(format!("1abc2\nsession {}\n", SESSION))
+            .create();
+        server
+            .mock("POST", "/2023/day/1/answer")
+            .with_status(200)
+            .with_body("<html><body><main>That's the right answer!</main></body></html>")
+            .create();
+
+        let recorder = client(&server.url(), FixtureMode::Record(dir.path().into()));
+        let input = recorder.get_input(2023, 1, SESSION).unwrap();
+        assert!(input.starts_with("1abc2"));
+        recorder.submit_answer(2023, 1, 1, "142", SESSION).unwrap();
+
+        for entry in fs::read_dir(dir.path()).unwrap() {
+            let contents = fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(!contents.contains(SESSION), "session leaked: {}", contents);
+            assert!(
+                !contents.contains("rotated"),
+                "set-cookie leaked: {}",
+                contents
+            );
+        }
+        let recorded = fs::read_to_string(dir.path().join("get_2023_day_1_input.json")).unwrap();
+        assert!(recorded.contains("session=<redacted>; Path=/; Secure; HttpOnly"));
+
+        // Nothing listens on the base URL any more
+        let url = server.url();
+        drop(server);
+        let replayer = client(&url, FixtureMode::Replay(dir.path().into()));
+        assert_eq!(
+            replayer.get_input(2023, 1, "other").unwrap(),
+            format!("1abc2\nsession {}\n", REDACTED)
+        );
+        assert_eq!(
+            replayer.submit_answer(2023, 1, 1, "142", "other").unwrap(),
+            SubmissionResult::Correct
+        );
+    }
+
+    #[test]
+    fn test_replay_missing_fixture_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let replayer = client("http://127.0.0.1:9", FixtureMode::Replay(dir.path().into()));
+        let result = replayer.get_input(2023, 2, SESSION);
+        assert!(matches!(result, Err(AocError::Fixture(_))));
+    }
+
+    #[test]
+    fn test_replay_committed_fixtures() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/http");
+        let replayer = client("https://adventofcode.com", FixtureMode::Replay(dir));
+        assert_eq!(
+            replayer.verify_session(SESSION).unwrap().user_id,
+            Some(123456)
+        );
+        assert_eq!(
+            replayer.get_input(2023, 1, SESSION).unwrap(),
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"
+        );
+    }
+}
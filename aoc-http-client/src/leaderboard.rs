@@ -0,0 +1,155 @@
+//! Private leaderboard models
+//!
+//! Mirrors the JSON served at `/{year}/leaderboard/private/view/{id}.json`.
+//! Timestamps are Unix seconds, as provided by AOC.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Minimum time between requests for the same private leaderboard
+///
+/// AOC asks that private leaderboards are fetched at most once every 15 minutes.
+pub const LEADERBOARD_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard for one event year
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrivateLeaderboard {
+    /// The event year, e.g. "2023"
+    pub event: String,
+    /// User ID of the leaderboard owner
+    pub owner_id: u64,
+    /// Members keyed by user ID
+    pub members: BTreeMap<u64, LeaderboardMember>,
+}
+
+/// A member of a private leaderboard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardMember {
+    /// User ID
+    pub id: u64,
+    /// Display name (None for anonymous users)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Total stars collected
+    pub stars: u32,
+    /// Score on this private leaderboard
+    pub local_score: u64,
+    /// Score on the global leaderboard
+    #[serde(default)]
+    pub global_score: u64,
+    /// When the member last collected a star (0 if never)
+    #[serde(default)]
+    pub last_star_ts: i64,
+    /// Star completion keyed by day
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, DayCompletion>,
+}
+
+/// Stars collected by a member on one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayCompletion {
+    /// Part 1 star
+    #[serde(rename = "1")]
+    pub part1: Star,
+    /// Part 2 star, if collected
+    #[serde(rename = "2", default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Star>,
+}
+
+/// A collected star
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Star {
+    /// When the star was collected
+    pub get_star_ts: i64,
+    /// Global order in which stars were collected
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+impl PrivateLeaderboard {
+    /// Members ordered by local score (highest first), then by last star time
+    pub fn standings(&self) -> Vec<&LeaderboardMember> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+}
+
+impl LeaderboardMember {
+    /// Name to display, falling back to AOC's anonymous user label
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// When the star for a day and part was collected, if it was
+    pub fn get_star_ts(&self, day: u8, part: u8) -> Option<i64> {
+        let completion = self.completion_day_level.get(&day)?;
+        match part {
+            1 => Some(completion.part1.get_star_ts),
+            2 => completion.part2.map(|s| s.get_star_ts),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_JSON: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "day1_ts": 1701406800,
+        "members": {
+            "1": {
+                "id": 1, "name": "alice", "stars": 3, "local_score": 7, "global_score": 0,
+                "last_star_ts": 1701493200,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701407100, "star_index": 10},
+                          "2": {"get_star_ts": 1701407400, "star_index": 20}},
+                    "2": {"1": {"get_star_ts": 1701493200, "star_index": 30}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_private_leaderboard() {
+        let board: PrivateLeaderboard = serde_json::from_str(SAMPLE_JSON).unwrap();
+        assert_eq!(board.event, "2023");
+        assert_eq!(board.members.len(), 2);
+
+        let alice = &board.members[&1];
+        assert_eq!(alice.get_star_ts(1, 1), Some(1701407100));
+        assert_eq!(alice.get_star_ts(1, 2), Some(1701407400));
+        assert_eq!(alice.get_star_ts(2, 2), None);
+        assert_eq!(alice.get_star_ts(3, 1), None);
+
+        assert_eq!(board.members[&2].display_name(), "(anonymous user #2)");
+        let order: Vec<u64> = board.standings().iter().map(|m| m.id).collect();
+        assert_eq!(order, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let board: PrivateLeaderboard = serde_json::from_str(SAMPLE_JSON).unwrap();
+        let json = serde_json::to_string(&board).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PrivateLeaderboard>(&json).unwrap(),
+            board
+        );
+    }
+}
@@ -7,9 +7,17 @@
 //!
 //! - Session validation to check if your AOC cookie is valid
 //! - Puzzle input fetching for any year and day
+//! - Puzzle descriptions rendered as Markdown
+//! - Confirmed answers scraped from solved puzzle pages
+//! - Star progress per year from the calendar page
+//! - Puzzle unlock times, checked against a swappable [`Clock`]
+//! - Private leaderboards as typed models, cached per AOC's request interval
 //! - Answer submission with detailed feedback
+//! - Contact User-Agent, optional request pacing and retries with backoff
+//! - Record/replay of HTTP exchanges for offline tests
 //! - Secure TLS using rustls (no OpenSSL dependencies)
-//! - Blocking synchronous API
+//! - Blocking synchronous API, plus `AsyncAocClient` behind the `async` feature
+//! - [`AocApi`] trait with an in-memory `FakeAocApi` behind the `fake` feature
 //! - Well-typed errors using thiserror
 //!
 //! # Example
@@ -19,7 +27,7 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! // Create a client
-//! let client = AocClient::new()?;
+//! let client = AocClient::new("github.com/me/aoc by me@example.com")?;
 //!
 //! // Your session cookie from adventofcode.com
 //! let session = "your_session_cookie_here";
@@ -37,19 +45,46 @@
 //! let result = client.submit_answer(2024, 1, 1, "42", session)?;
 //! match result {
 //!     SubmissionResult::Correct => println!("Correct!"),
-//!     SubmissionResult::Incorrect => println!("Incorrect"),
+//!     SubmissionResult::Incorrect { hint } => println!("Incorrect ({:?})", hint),
 //!     SubmissionResult::AlreadyCompleted => println!("Already done"),
+//!     SubmissionResult::WrongLevel => println!("Wrong part"),
 //!     SubmissionResult::Throttled { wait_time } => {
 //!         println!("Throttled: {:?}", wait_time);
 //!     }
+//!     SubmissionResult::Unknown(text) => println!("Unrecognised response: {}", text),
 //! }
 //! # Ok(())
 //! # }
 //! ```
 
+mod api;
+#[cfg(feature = "async")]
+mod async_client;
+mod calendar;
 mod client;
 mod error;
+#[cfg(feature = "fake")]
+mod fake;
+mod fixtures;
+mod leaderboard;
 mod parser;
+mod puzzle;
+mod request;
+mod unlock;
 
-pub use client::{AocClient, AocClientBuilder, SessionInfo, SubmissionResult};
+pub use api::AocApi;
+#[cfg(feature = "async")]
+pub use async_client::{AsyncAocClient, AsyncAocClientBuilder};
+pub use calendar::YearProgress;
+pub use client::{AnswerHint, AocClient, AocClientBuilder, SessionInfo, SubmissionResult};
 pub use error::AocError;
+#[cfg(feature = "fake")]
+pub use fake::{FakeAocApi, FakeCall};
+pub use fixtures::{FixtureMode, REDACTED, fixture_name};
+pub use leaderboard::{
+    DayCompletion, LEADERBOARD_REFRESH_INTERVAL, LeaderboardMember, PrivateLeaderboard, Star,
+};
+pub use parser::check_input;
+pub use puzzle::{DayAnswers, Example, Puzzle, PuzzlePart};
+pub use request::{DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_RETRIES};
+pub use unlock::{Clock, SystemClock, is_unlocked, time_until_unlock, unlock_time};
@@ -1,8 +1,11 @@
 //! HTML response parsing utilities
 
-use crate::{SubmissionResult, error::AocError};
+use crate::{
+    AnswerHint, Example, Puzzle, PuzzlePart, SubmissionResult, YearProgress, error::AocError,
+};
 use regex::Regex;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 /// Parser for AOC HTML responses with cached regex patterns and selectors
@@ -10,7 +13,13 @@ use std::time::Duration;
 pub(crate) struct ResponseParser {
     user_id_regex: Regex,
     throttle_regex: Regex,
+    hint_regex: Regex,
+    title_regex: Regex,
     main_selector: Selector,
+    heading_selector: Selector,
+    code_selector: Selector,
+    calendar_day_selector: Selector,
+    day_href_regex: Regex,
 }
 
 impl ResponseParser {
@@ -19,7 +28,13 @@ impl ResponseParser {
         Self {
             user_id_regex: Regex::new(r"\(anonymous user #(\d+)\)").unwrap(),
             throttle_regex: Regex::new(r"You have (.+?) left to wait\.").unwrap(),
+            hint_regex: Regex::new(r"your answer is too (high|low)").unwrap(),
+            title_regex: Regex::new(r"--- Day \d+: (.+?) ---").unwrap(),
             main_selector: Selector::parse("main").unwrap(),
+            heading_selector: Selector::parse("h2").unwrap(),
+            code_selector: Selector::parse("code").unwrap(),
+            calendar_day_selector: Selector::parse("a[class*=calendar-day]").unwrap(),
+            day_href_regex: Regex::new(r"/day/(\d+)$").unwrap(),
         }
     }
 
@@ -52,28 +67,356 @@ impl ResponseParser {
         humantime::parse_duration(duration_str).ok()
     }
 
+    /// Extract the too high/too low hint from an incorrect answer message
+    fn extract_answer_hint(&self, text: &str) -> Option<AnswerHint> {
+        let captures = self.hint_regex.captures(text)?;
+        match captures.get(1)?.as_str() {
+            "high" => Some(AnswerHint::TooHigh),
+            _ => Some(AnswerHint::TooLow),
+        }
+    }
+
     /// Parse submission response and determine the result
+    ///
+    /// Only explicitly recognised messages map to an outcome; anything else is
+    /// returned as `Unknown` with the page's main text. A login page is reported
+    /// as `AocError::SessionExpired`.
     pub fn parse_submission_response(&self, html: &str) -> Result<SubmissionResult, AocError> {
         let text = self.extract_main_text(html)?;
 
+        // A login page means the session cookie was not accepted
+        if text.contains("please identify yourself") {
+            return Err(AocError::SessionExpired);
+        }
+
         // Check for incorrect answer
         if text.contains("not the right answer") {
-            return Ok(SubmissionResult::Incorrect);
+            let hint = self.extract_answer_hint(&text);
+            return Ok(SubmissionResult::Incorrect { hint });
         }
 
-        // Check for already completed
+        // AoC answers a completed level with "You don't seem to be solving the
+        // right level. Did you already complete it?", so this must come first
         if text.contains("already complete it") {
             return Ok(SubmissionResult::AlreadyCompleted);
         }
 
+        // Check for a submission to a level that is not the current one
+        if text.contains("solving the right level") {
+            return Ok(SubmissionResult::WrongLevel);
+        }
+
         // Check for throttling
         if text.contains("gave an answer too recently") {
             let wait_time = self.extract_throttle_duration(&text);
             return Ok(SubmissionResult::Throttled { wait_time });
         }
 
-        // If none of the above, assume correct
-        Ok(SubmissionResult::Correct)
+        // Check for correct answer
+        if text.contains("That's the right answer") {
+            return Ok(SubmissionResult::Correct);
+        }
+
+        Ok(SubmissionResult::Unknown(
+            text.split_whitespace().collect::<Vec<_>>().join(" "),
+        ))
+    }
+}
+
+impl ResponseParser {
+    /// Parse a puzzle page into its title, part descriptions and revealed answers
+    ///
+    /// Each `<article class="day-desc">` in the main element is one part. A solved
+    /// part is followed by a "Your puzzle answer was <code>...</code>" paragraph.
+    pub fn parse_puzzle(&self, year: u16, day: u8, html: &str) -> Result<Puzzle, AocError> {
+        let document = Html::parse_document(html);
+        let main = document
+            .select(&self.main_selector)
+            .next()
+            .ok_or(AocError::HtmlParse)?;
+
+        let mut title = None;
+        let mut parts: Vec<PuzzlePart> = Vec::new();
+
+        for child in main.children().filter_map(ElementRef::wrap) {
+            match child.value().name() {
+                "article" if child.value().classes().any(|c| c == "day-desc") => {
+                    if title.is_none() {
+                        title = child
+                            .select(&self.heading_selector)
+                            .next()
+                            .map(|h| h.text().collect::<String>())
+                            .and_then(|h| Some(self.title_regex.captures(&h)?[1].to_string()));
+                    }
+                    parts.push(PuzzlePart {
+                        description: self.html_to_markdown(child),
+                        answer: None,
+                        examples: extract_examples(child),
+                    });
+                }
+                "p" if child
+                    .text()
+                    .collect::<String>()
+                    .contains("Your puzzle answer was") =>
+                {
+                    if let Some(part) = parts.last_mut() {
+                        part.answer = child
+                            .select(&self.code_selector)
+                            .next()
+                            .map(|code| code.text().collect());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if parts.is_empty() {
+            return Err(AocError::HtmlParse);
+        }
+
+        // Later parts usually reuse the previous part's example input and only
+        // highlight a new answer; pair that answer with the last known input
+        let mut last_input = String::new();
+        for part in &mut parts {
+            for example in &mut part.examples {
+                if example.input.is_empty() {
+                    example.input.clone_from(&last_input);
+                }
+            }
+            part.examples.retain(|e| !e.input.is_empty());
+            if let Some(example) = part.examples.last() {
+                last_input.clone_from(&example.input);
+            }
+        }
+
+        Ok(Puzzle {
+            year,
+            day,
+            title: title.ok_or(AocError::HtmlParse)?,
+            parts,
+        })
+    }
+
+    /// Parse a year's calendar page into stars per unlocked day
+    ///
+    /// Each unlocked day is an `<a class="calendar-dayN ...">` link; the
+    /// `calendar-complete` class marks one star and `calendar-verycomplete` two.
+    pub fn parse_calendar(&self, year: u16, html: &str) -> Result<YearProgress, AocError> {
+        let document = Html::parse_document(html);
+        let mut days = BTreeMap::new();
+
+        for link in document.select(&self.calendar_day_selector) {
+            let Some(day) = link
+                .value()
+                .attr("href")
+                .and_then(|href| self.day_href_regex.captures(href))
+                .and_then(|c| c[1].parse::<u8>().ok())
+            else {
+                continue;
+            };
+            let classes: Vec<_> = link.value().classes().collect();
+            let stars = if classes.contains(&"calendar-verycomplete") {
+                2
+            } else if classes.contains(&"calendar-complete") {
+                1
+            } else {
+                0
+            };
+            days.insert(day, stars);
+        }
+
+        // Every released year has at least day 1 unlocked; no links means this
+        // is not a calendar page (e.g. a login prompt)
+        if days.is_empty() {
+            return Err(AocError::HtmlParse);
+        }
+        Ok(YearProgress { year, days })
+    }
+
+    /// Convert the block content of an element to Markdown
+    ///
+    /// Handles the subset of HTML used in puzzle descriptions: headings, paragraphs,
+    /// `<pre>` code blocks, lists, inline `<code>`, `<em>` and links.
+    pub fn html_to_markdown(&self, element: ElementRef<'_>) -> String {
+        let mut out = String::new();
+        push_blocks(element, &mut out);
+        let len = out.trim_end().len();
+        out.truncate(len);
+        out.push('\n');
+        out
+    }
+}
+
+/// Collect example inputs and highlighted answers from a puzzle article
+///
+/// Each `<pre>` block starts a new example; the last highlighted value after it
+/// becomes its answer. Highlighted values in an article without any `<pre>` block
+/// produce a single example with an empty input, to be filled in by the caller.
+fn extract_examples(article: ElementRef<'_>) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut trailing_answer = None;
+    collect_examples(article, &mut examples, &mut trailing_answer);
+    if examples.is_empty()
+        && let Some(answer) = trailing_answer
+    {
+        examples.push(Example {
+            input: String::new(),
+            answer: Some(answer),
+        });
+    }
+    examples
+}
+
+fn collect_examples(
+    element: ElementRef<'_>,
+    examples: &mut Vec<Example>,
+    trailing_answer: &mut Option<String>,
+) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        if child.value().name() == "pre" {
+            examples.push(Example {
+                input: child.text().collect(),
+                answer: None,
+            });
+        } else if let Some(value) = highlighted_value(child) {
+            match examples.last_mut() {
+                Some(example) => example.answer = Some(value),
+                None => *trailing_answer = Some(value),
+            }
+        } else {
+            collect_examples(child, examples, trailing_answer);
+        }
+    }
+}
+
+/// The value of a highlighted `<code><em>x</em></code>` or `<em><code>x</code></em>`
+fn highlighted_value(element: ElementRef<'_>) -> Option<String> {
+    let inner = match element.value().name() {
+        "code" => "em",
+        "em" => "code",
+        _ => return None,
+    };
+    let mut children = element
+        .children()
+        .filter(|n| n.value().as_text().is_none_or(|t| !t.trim().is_empty()));
+    let only = ElementRef::wrap(children.next()?)?;
+    if children.next().is_some() || only.value().name() != inner {
+        return None;
+    }
+    let value: String = only.text().collect();
+    (!value.trim().is_empty()).then(|| value.trim().to_string())
+}
+
+/// Append block-level children of `element` as Markdown, separated by blank lines
+fn push_blocks(element: ElementRef<'_>, out: &mut String) {
+    for node in element.children() {
+        if let Some(text) = node.value().as_text() {
+            let text = text.trim();
+            if !text.is_empty() {
+                out.push_str(text);
+                out.push_str("\n\n");
+            }
+            continue;
+        }
+        let Some(child) = ElementRef::wrap(node) else {
+            continue;
+        };
+        match child.value().name() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                out.push_str("## ");
+                out.push_str(inline_markdown(child).trim());
+                out.push_str("\n\n");
+            }
+            "pre" => {
+                let code = child.text().collect::<String>();
+                out.push_str("```\n");
+                out.push_str(code.trim_end_matches('\n'));
+                out.push_str("\n```\n\n");
+            }
+            tag @ ("ul" | "ol") => {
+                let items = child
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|li| li.value().name() == "li");
+                for (i, item) in items.enumerate() {
+                    if tag == "ol" {
+                        out.push_str(&format!("{}. ", i + 1));
+                    } else {
+                        out.push_str("- ");
+                    }
+                    out.push_str(inline_markdown(item).trim());
+                    out.push('\n');
+                }
+                out.push('\n');
+            }
+            _ => {
+                let text = inline_markdown(child);
+                let text = text.trim();
+                if !text.is_empty() {
+                    out.push_str(text);
+                    out.push_str("\n\n");
+                }
+            }
+        }
+    }
+}
+
+/// Render inline content of `element` as Markdown
+fn inline_markdown(element: ElementRef<'_>) -> String {
+    let mut out = String::new();
+    for node in element.children() {
+        if let Some(text) = node.value().as_text() {
+            out.push_str(text);
+            continue;
+        }
+        let Some(child) = ElementRef::wrap(node) else {
+            continue;
+        };
+        match child.value().name() {
+            "code" => {
+                out.push('`');
+                out.push_str(&child.text().collect::<String>());
+                out.push('`');
+            }
+            "em" | "strong" | "b" | "i" => {
+                out.push('*');
+                out.push_str(&inline_markdown(child));
+                out.push('*');
+            }
+            "a" => match child.value().attr("href") {
+                Some(href) => out.push_str(&format!("[{}]({})", inline_markdown(child), href)),
+                None => out.push_str(&inline_markdown(child)),
+            },
+            "br" => out.push('\n'),
+            _ => out.push_str(&inline_markdown(child)),
+        }
+    }
+    out
+}
+
+/// Check that a body returned by the input endpoint is puzzle input
+///
+/// AoC answers input requests it cannot serve with short messages or error pages,
+/// sometimes with a 200 status. These are recognised so they are never mistaken
+/// for (and cached as) puzzle input.
+///
+/// # Errors
+///
+/// * `AocError::NotYetUnlocked` - The "before it unlocks" message
+/// * `AocError::SessionExpired` - The "Please log in" message
+/// * `AocError::PuzzleNotFound` - A "404 Not Found" page
+pub fn check_input(body: &str) -> Result<(), AocError> {
+    let head = body.trim_start();
+    if head.contains("request this endpoint before it unlocks") {
+        Err(AocError::NotYetUnlocked)
+    } else if head.contains("Please log in to get your puzzle input") {
+        Err(AocError::SessionExpired)
+    } else if head.starts_with("404 Not Found")
+        || (head.starts_with('<') && head.contains("404 Not Found"))
+    {
+        Err(AocError::PuzzleNotFound)
+    } else {
+        Ok(())
     }
 }
 
@@ -88,6 +431,137 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    const PUZZLE_HTML: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 5: If You Give A Seed A Fertilizer ---</h2>
+<p>You take the boat and find the <em>gardener</em> right where you were told.</p>
+<pre><code>seeds: 79 14 55 13
+</code></pre>
+<ul><li>Seed <code>79</code>, soil <code>81</code>.</li><li>See <a href="/2023/day/4">yesterday</a>.</li></ul>
+</article>
+<p>Your puzzle answer was <code>178159714</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Everyone will starve.</p></article>
+<p>Your puzzle answer was <code>100165128</code>.</p>
+<p>Both parts of this puzzle are complete!</p>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let parser = ResponseParser::new();
+        let puzzle = parser.parse_puzzle(2023, 5, PUZZLE_HTML).unwrap();
+
+        assert_eq!(puzzle.title, "If You Give A Seed A Fertilizer");
+        assert_eq!(puzzle.parts.len(), 2);
+        assert_eq!(puzzle.parts[0].answer.as_deref(), Some("178159714"));
+        assert_eq!(puzzle.parts[1].answer.as_deref(), Some("100165128"));
+        assert_eq!(
+            puzzle.parts[0].description,
+            "## --- Day 5: If You Give A Seed A Fertilizer ---\n\n\
+             You take the boat and find the *gardener* right where you were told.\n\n\
+             ```\nseeds: 79 14 55 13\n```\n\n\
+             - Seed `79`, soil `81`.\n\
+             - See [yesterday](/2023/day/4).\n"
+        );
+        assert_eq!(
+            puzzle.parts[1].description,
+            "## --- Part Two ---\n\nEveryone will starve.\n"
+        );
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        let parser = ResponseParser::new();
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+</code></pre>
+<p>The values are <code>12</code> and <code>38</code>; adding these produces <code><em>50</em></code>.</p>
+<p>Another example:</p>
+<pre><code>two1nine
+</code></pre>
+<p>In this example the sum is <em><code>29</code></em>.</p>
+</article>
+<article class="day-desc"><h2>--- Part Two ---</h2>
+<p>Using the same example, the answer is now <code><em>77</em></code>.</p>
+</article>
+</main></body></html>"#;
+        let puzzle = parser.parse_puzzle(2023, 1, html).unwrap();
+
+        assert_eq!(
+            puzzle.parts[0].examples,
+            vec![
+                Example {
+                    input: "1abc2\npqr3stu8vwx\n".to_string(),
+                    answer: Some("50".to_string()),
+                },
+                Example {
+                    input: "two1nine\n".to_string(),
+                    answer: Some("29".to_string()),
+                },
+            ]
+        );
+        // Part two reuses the last example input from part one
+        assert_eq!(
+            puzzle.parts[1].examples,
+            vec![Example {
+                input: "two1nine\n".to_string(),
+                answer: Some("77".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_calendar() {
+        let parser = ResponseParser::new();
+        let html = r#"<html><body><main><pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">art <span class="calendar-day"> 1</span></a>
+<a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">art <span class="calendar-day"> 2</span></a>
+<a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">art <span class="calendar-day"> 3</span></a>
+<span aria-hidden="true" class="calendar-day4">art <span class="calendar-day"> 4</span></span>
+</pre></main></body></html>"#;
+        let progress = parser.parse_calendar(2023, html).unwrap();
+
+        assert_eq!(progress.days, BTreeMap::from([(1, 2), (2, 1), (3, 0)]));
+        assert!(progress.is_completed(1, 2));
+        assert!(progress.is_completed(2, 1));
+        assert!(!progress.is_completed(2, 2));
+        assert!(!progress.is_unlocked(4));
+        assert_eq!(progress.total_stars(), 3);
+    }
+
+    #[test]
+    fn test_parse_calendar_without_days() {
+        let parser = ResponseParser::new();
+        let html = r#"<html><body><main><p>[Log In]</p></main></body></html>"#;
+        assert!(matches!(
+            parser.parse_calendar(2023, html),
+            Err(AocError::HtmlParse)
+        ));
+    }
+
+    #[test]
+    fn test_parse_puzzle_unsolved() {
+        let parser = ResponseParser::new();
+        let html = r#"<html><body><main><article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Text</p></article><form method="post"></form></main></body></html>"#;
+        let puzzle = parser.parse_puzzle(2023, 1, html).unwrap();
+
+        assert_eq!(puzzle.title, "Trebuchet?!");
+        assert_eq!(puzzle.parts.len(), 1);
+        assert_eq!(puzzle.parts[0].answer, None);
+        assert!(puzzle.part(2).is_none());
+    }
+
+    #[test]
+    fn test_parse_puzzle_without_articles() {
+        let parser = ResponseParser::new();
+        let html = r#"<html><body><main><p>Please don't repeatedly request this endpoint</p></main></body></html>"#;
+        assert!(matches!(
+            parser.parse_puzzle(2023, 1, html),
+            Err(AocError::HtmlParse)
+        ));
+    }
+
     #[test]
     fn test_malformed_html() {
         let parser = ResponseParser::new();
@@ -128,8 +602,108 @@ mod tests {
         let parser = ResponseParser::new();
         let html = r#"<html><body><main></main></body></html>"#;
         let result = parser.parse_submission_response(html).unwrap();
-        // Empty main should default to Correct
-        assert_eq!(result, SubmissionResult::Correct);
+        // Empty main is not recognised as any outcome
+        assert_eq!(result, SubmissionResult::Unknown(String::new()));
+    }
+
+    /// Client answering from the exchanges recorded in `tests/fixtures/http`
+    fn replayer() -> crate::AocClient {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/http");
+        crate::AocClient::builder("aoc-http-client tests")
+            .fixtures(crate::FixtureMode::Replay(dir))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_submission_fixtures() {
+        let cases = [
+            (1, "142", SubmissionResult::Correct),
+            (2, "281", SubmissionResult::Correct),
+            (1, "12345", SubmissionResult::Incorrect { hint: None }),
+            (
+                1,
+                "999",
+                SubmissionResult::Incorrect {
+                    hint: Some(AnswerHint::TooHigh),
+                },
+            ),
+            (
+                1,
+                "100",
+                SubmissionResult::Incorrect {
+                    hint: Some(AnswerHint::TooLow),
+                },
+            ),
+            (1, "54", SubmissionResult::AlreadyCompleted),
+            (
+                1,
+                "2",
+                SubmissionResult::Throttled {
+                    wait_time: Some(Duration::from_secs(34)),
+                },
+            ),
+            (
+                1,
+                "3",
+                SubmissionResult::Throttled {
+                    wait_time: Some(Duration::from_secs(252)),
+                },
+            ),
+        ];
+        let client = replayer();
+        for (level, answer, expected) in cases {
+            let result = client.submit_answer(2023, 1, level, answer, "session");
+            assert_eq!(
+                result.unwrap(),
+                expected,
+                "level {} answer {}",
+                level,
+                answer
+            );
+        }
+    }
+
+    #[test]
+    fn test_submission_fixture_login_page_is_session_expired() {
+        let result = replayer().submit_answer(2023, 1, 1, "1", "session");
+        assert!(matches!(result, Err(AocError::SessionExpired)));
+    }
+
+    #[test]
+    fn test_real_right_level_page_is_already_completed() {
+        // The page AoC serves for a completed level contains both phrases
+        let parser = ResponseParser::new();
+        let html = r#"<main><article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2023/day/1">[Return to Day 1]</a></p></article></main>"#;
+        assert_eq!(
+            parser.parse_submission_response(html).unwrap(),
+            SubmissionResult::AlreadyCompleted
+        );
+
+        let html = r#"<main><article><p>You don't seem to be solving the right level.</p></article></main>"#;
+        assert_eq!(
+            parser.parse_submission_response(html).unwrap(),
+            SubmissionResult::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_input_error_fixtures() {
+        let client = replayer();
+        assert!(matches!(
+            client.get_input(2030, 1, "session"),
+            Err(AocError::NotYetUnlocked)
+        ));
+        assert!(matches!(
+            client.get_input(2023, 3, "session"),
+            Err(AocError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.get_input(2014, 1, "session"),
+            Err(AocError::PuzzleNotFound)
+        ));
+        assert!(check_input("1abc2\npqr3stu8vwx\n").is_ok());
+        assert!(check_input("").is_ok());
     }
 
     // **Feature: aoc-http-client, Property 9: HTML main element extraction**
@@ -263,7 +837,7 @@ mod tests {
 
             prop_assert_eq!(
                 result.unwrap(),
-                SubmissionResult::Incorrect,
+                SubmissionResult::Incorrect { hint: None },
                 "HTML containing 'not the right answer' should return SubmissionResult::Incorrect"
             );
         }
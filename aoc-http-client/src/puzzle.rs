@@ -0,0 +1,85 @@
+//! Puzzle description types
+
+use std::fmt;
+
+/// A puzzle page: title and the descriptions of every revealed part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The AOC year
+    pub year: u16,
+    /// The day number (1-25)
+    pub day: u8,
+    /// Puzzle title, e.g. "If You Give A Seed A Fertilizer"
+    pub title: String,
+    /// Revealed parts in order; part 2 only appears after part 1 is solved
+    pub parts: Vec<PuzzlePart>,
+}
+
+/// One part of a puzzle description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePart {
+    /// Article body converted to Markdown
+    pub description: String,
+    /// The accepted answer, if the part has been solved ("Your puzzle answer was ...")
+    pub answer: Option<String>,
+    /// Candidate example inputs found in the description, with their expected answers
+    pub examples: Vec<Example>,
+}
+
+/// An example input from a puzzle description
+///
+/// Extracted heuristically: the input is a `<pre><code>` block and the expected
+/// answer is the last highlighted `<code><em>` value that follows it in the same
+/// part. Both should be checked against the description before relying on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The example input, exactly as shown in the code block
+    pub input: String,
+    /// The expected answer for the part, if one was highlighted after the input
+    pub answer: Option<String>,
+}
+
+/// Answers accepted by AOC for one day, as shown on its puzzle page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayAnswers {
+    /// The AOC year
+    pub year: u16,
+    /// The day number (1-25)
+    pub day: u8,
+    /// Confirmed answers in part order (index 0 is part 1)
+    pub answers: Vec<String>,
+}
+
+impl Puzzle {
+    /// Get a part by number (1-based)
+    pub fn part(&self, part: u8) -> Option<&PuzzlePart> {
+        self.parts.get(usize::from(part).checked_sub(1)?)
+    }
+
+    /// Confirmed answers for the solved parts, in part order
+    pub fn answers(&self) -> DayAnswers {
+        DayAnswers {
+            year: self.year,
+            day: self.day,
+            answers: self.parts.iter().map_while(|p| p.answer.clone()).collect(),
+        }
+    }
+
+    /// Render the whole puzzle as a Markdown document
+    pub fn to_markdown(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "# {} Day {}: {}", self.year, self.day, self.title)?;
+        for part in &self.parts {
+            write!(f, "\n\n{}", part.description.trim_end())?;
+            if let Some(answer) = &part.answer {
+                write!(f, "\n\nYour puzzle answer was `{}`.", answer)?;
+            }
+        }
+        writeln!(f)
+    }
+}
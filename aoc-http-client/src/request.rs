@@ -0,0 +1,182 @@
+//! Request pacing, retry policy and headers shared by the blocking and async clients
+
+use crate::error::AocError;
+use reqwest::header::HeaderValue;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use zeroize::Zeroize;
+
+/// Default number of retries for transient failures
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default delay before the first retry; doubled for each further retry
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound for a single backoff delay
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Enforces a minimum interval between request starts
+///
+/// Each caller reserves the next free slot and sleeps until it without holding
+/// the lock, so concurrent callers are spaced out rather than serialized behind
+/// one sleeper.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Reserve the next request slot, returning how long to wait for it
+    pub fn reserve(&self) -> Duration {
+        if self.interval.is_zero() {
+            return Duration::ZERO;
+        }
+        let now = Instant::now();
+        let mut next_slot = self.next_slot.lock().unwrap();
+        let slot = next_slot.map_or(now, |next| next.max(now));
+        *next_slot = Some(slot + self.interval);
+        slot - now
+    }
+
+    /// Block until this caller may start a request
+    pub fn wait(&self) {
+        let delay = self.reserve();
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+    }
+}
+
+/// How often and how patiently failed requests are retried
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `attempt` (starting at 0)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_BACKOFF)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+        }
+    }
+}
+
+/// Whether a failed attempt is worth retrying
+///
+/// Only GET requests are retried on 5xx responses and timeouts; a POST is only
+/// retried when the connection could not be made, since a failed response does
+/// not prove the answer was not recorded. 4xx responses are never retried.
+pub(crate) fn is_transient(
+    idempotent: bool,
+    result: &Result<reqwest::StatusCode, &reqwest::Error>,
+) -> bool {
+    match result {
+        Ok(status) => idempotent && status.is_server_error(),
+        Err(e) => e.is_connect() || (idempotent && e.is_timeout()),
+    }
+}
+
+/// The adventofcode.com base URL used unless a client is configured otherwise
+pub(crate) fn default_base_url() -> reqwest::Url {
+    reqwest::Url::parse("https://adventofcode.com")
+        .expect("Default base URL should always be valid")
+}
+
+/// Build the User-Agent header identifying the operator
+pub(crate) fn user_agent(contact: &str) -> Result<HeaderValue, AocError> {
+    let contact = contact.trim();
+    if contact.is_empty() {
+        return Err(AocError::ClientInit(
+            "A contact for the User-Agent header is required".to_string(),
+        ));
+    }
+    HeaderValue::from_str(&format!(
+        "aoc-http-client/{} ({})",
+        env!("CARGO_PKG_VERSION"),
+        contact
+    ))
+    .map_err(|_| AocError::ClientInit("Invalid User-Agent contact".to_string()))
+}
+
+/// Create a secure cookie header value from a session string
+///
+/// This function creates a HeaderValue with the sensitive flag set to true
+/// and zeroizes the temporary string after use.
+pub(crate) fn cookie_header(session: &str) -> Result<HeaderValue, AocError> {
+    let mut cookie_string = format!("session={}", session);
+    let header_value = HeaderValue::from_bytes(cookie_string.as_bytes())
+        .map_err(|_| AocError::ClientInit("Invalid session cookie format".to_string()));
+    cookie_string.zeroize();
+
+    // Mark as sensitive so it is redacted from debug output
+    let mut sensitive_header = header_value?;
+    sensitive_header.set_sensitive(true);
+    Ok(sensitive_header)
+}
+
+/// Append path segments to the base URL, replacing its path
+pub(crate) fn endpoint(
+    base_url: &reqwest::Url,
+    segments: &[&str],
+) -> Result<reqwest::Url, AocError> {
+    let mut url = base_url.clone();
+    url.path_segments_mut()
+        .map_err(|_| AocError::ClientInit("Cannot modify base URL path".to_string()))?
+        .clear()
+        .extend(segments);
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(500),
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(40), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_rate_limiter_spaces_concurrent_requests() {
+        let limiter = Arc::new(RateLimiter::new(Duration::from_millis(50)));
+        let start = Instant::now();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let limiter = Arc::clone(&limiter);
+                std::thread::spawn(move || limiter.wait())
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        // The first request goes immediately, the other three wait 50ms each
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+}
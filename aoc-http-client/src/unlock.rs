@@ -0,0 +1,114 @@
+//! Puzzle unlock times
+//!
+//! Puzzles unlock at midnight US Eastern time. Advent of Code runs in December,
+//! when Eastern time is always UTC-5, so day `n` unlocks at 05:00 UTC on
+//! December `n`.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Source of the current time
+///
+/// Lets unlock checks and countdowns be tested without depending on the real
+/// time: a test clock can return any instant and advance itself on `sleep`.
+pub trait Clock {
+    /// The current time
+    fn now(&self) -> SystemTime;
+
+    /// Wait for a duration (blocks the thread by default)
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// The system's real-time clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// The instant a puzzle unlocks: 05:00 UTC on December `day` of `year`
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day));
+    let secs = days * 86_400 + 5 * 3_600;
+    UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64)
+}
+
+/// Whether a puzzle is available according to `clock`
+pub fn is_unlocked(year: u16, day: u8, clock: &impl Clock) -> bool {
+    time_until_unlock(year, day, clock).is_none()
+}
+
+/// How long until a puzzle unlocks, or `None` if it already has
+pub fn time_until_unlock(year: u16, day: u8, clock: &impl Clock) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(clock.now())
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClock(SystemTime);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> SystemTime {
+            self.0
+        }
+    }
+
+    fn at(secs: u64) -> FixedClock {
+        FixedClock(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2023-12-01 05:00:00 UTC
+        assert_eq!(
+            unlock_time(2023, 1),
+            UNIX_EPOCH + Duration::from_secs(1_701_406_800)
+        );
+        // 2024-12-25 05:00:00 UTC (leap year)
+        assert_eq!(
+            unlock_time(2024, 25),
+            UNIX_EPOCH + Duration::from_secs(1_735_102_800)
+        );
+    }
+
+    #[test]
+    fn test_is_unlocked_around_release() {
+        let unlock = 1_701_406_800;
+        assert!(!is_unlocked(2023, 1, &at(unlock - 1)));
+        assert!(is_unlocked(2023, 1, &at(unlock)));
+        assert!(is_unlocked(2023, 1, &at(unlock + 1)));
+    }
+
+    #[test]
+    fn test_time_until_unlock() {
+        let unlock = 1_701_406_800;
+        assert_eq!(
+            time_until_unlock(2023, 1, &at(unlock - 90)),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(time_until_unlock(2023, 1, &at(unlock)), None);
+        assert_eq!(
+            time_until_unlock(2023, 2, &at(unlock)),
+            Some(Duration::from_secs(86_400))
+        );
+    }
+}
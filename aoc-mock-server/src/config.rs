@@ -0,0 +1,162 @@
+//! Server state loaded from a config directory
+//!
+//! ```text
+//! server.json                      {"sessions": {"<token>": <user id>}, "throttle_secs": 60}
+//! {year}/{day}/input.txt           puzzle input, served to every user
+//! {year}/{day}/answers.txt         correct answers, one line per part
+//! {year}/{day}/part{N}.html        optional article body of part N
+//! {year}/leaderboard/{id}.json     private leaderboard JSON
+//! ```
+
+use crate::error::MockError;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Name of the file holding sessions and the throttle window
+pub const SERVER_FILE: &str = "server.json";
+
+/// Throttle window after a wrong answer when `server.json` does not set one
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+struct ServerFile {
+    #[serde(default)]
+    sessions: HashMap<String, u64>,
+    #[serde(default)]
+    throttle_secs: Option<u64>,
+}
+
+/// A puzzle the server knows about
+#[derive(Debug, Clone)]
+pub struct MockPuzzle {
+    /// Input served at `/{year}/day/{day}/input`
+    pub input: String,
+    /// Correct answer of each part, in order
+    pub answers: Vec<String>,
+    /// Article body of each part; generated when not configured
+    pub articles: Vec<String>,
+}
+
+/// Everything the server serves, keyed like the AoC URLs
+#[derive(Debug, Clone, Default)]
+pub struct MockConfig {
+    /// Accepted session cookies and the user each belongs to
+    pub sessions: HashMap<String, u64>,
+    /// How long a user must wait after a wrong answer
+    pub throttle: Duration,
+    /// Puzzles by year and day
+    pub puzzles: BTreeMap<(u16, u8), MockPuzzle>,
+    /// Private leaderboard JSON by year and leaderboard ID
+    pub leaderboards: HashMap<(u16, u64), String>,
+}
+
+impl MockConfig {
+    /// Load the server state from a config directory
+    ///
+    /// # Errors
+    ///
+    /// `MockError::Config` for a missing `server.json`, a puzzle without
+    /// `input.txt` or `answers.txt`, or entries that are not years, days or
+    /// leaderboard IDs.
+    pub fn load(dir: &Path) -> Result<Self, MockError> {
+        let server_path = dir.join(SERVER_FILE);
+        if !server_path.exists() {
+            return Err(MockError::Config(format!(
+                "{} not found",
+                server_path.display()
+            )));
+        }
+        let server: ServerFile = serde_json::from_str(&fs::read_to_string(server_path)?)?;
+
+        let mut config = Self {
+            sessions: server.sessions,
+            throttle: server
+                .throttle_secs
+                .map_or(DEFAULT_THROTTLE, Duration::from_secs),
+            ..Self::default()
+        };
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let year: u16 = parse_name(&path)?;
+            for entry in fs::read_dir(&path)? {
+                let path = entry?.path();
+                if path.file_name().is_some_and(|name| name == "leaderboard") {
+                    config.load_leaderboards(year, &path)?;
+                } else if path.is_dir() {
+                    let day: u8 = parse_name(&path)?;
+                    config.puzzles.insert((year, day), load_puzzle(day, &path)?);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    fn load_leaderboards(&mut self, year: u16, dir: &Path) -> Result<(), MockError> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let id: u64 = parse_name(&path)?;
+            self.leaderboards
+                .insert((year, id), fs::read_to_string(&path)?);
+        }
+        Ok(())
+    }
+}
+
+/// Parse a file or directory name (without extension) as a number
+fn parse_name<T: std::str::FromStr>(path: &Path) -> Result<T, MockError> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.parse().ok())
+        .ok_or_else(|| MockError::Config(format!("unexpected entry {}", path.display())))
+}
+
+fn load_puzzle(day: u8, dir: &Path) -> Result<MockPuzzle, MockError> {
+    let read = |name: &str| {
+        let path = dir.join(name);
+        fs::read_to_string(&path)
+            .map_err(|e| MockError::Config(format!("cannot read {}: {}", path.display(), e)))
+    };
+    let input = read("input.txt")?;
+    let answers: Vec<String> = read("answers.txt")?
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    let mut articles = Vec::with_capacity(answers.len());
+    for part in 1..=answers.len() {
+        let path = dir.join(format!("part{}.html", part));
+        articles.push(if path.exists() {
+            fs::read_to_string(path)?
+        } else {
+            default_article(day, part)
+        });
+    }
+    Ok(MockPuzzle {
+        input,
+        answers,
+        articles,
+    })
+}
+
+fn default_article(day: u8, part: usize) -> String {
+    let heading = if part == 1 {
+        format!("--- Day {}: Mock Puzzle ---", day)
+    } else {
+        "--- Part Two ---".to_string()
+    };
+    format!(
+        "<h2>{}</h2><p>Part {} of a puzzle served by aoc-mock-server.</p>",
+        heading, part
+    )
+}
@@ -0,0 +1,23 @@
+//! Error types for the mock server
+
+use thiserror::Error;
+
+/// Errors loading the server state or starting the server
+#[derive(Error, Debug)]
+pub enum MockError {
+    /// A file in the config directory could not be read
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// `server.json` is not valid
+    #[error("Invalid server.json: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The config directory does not have the expected layout
+    #[error("Invalid config directory: {0}")]
+    Config(String),
+
+    /// The listening socket could not be opened
+    #[error("Failed to bind {addr}: {message}")]
+    Bind { addr: String, message: String },
+}
@@ -0,0 +1,30 @@
+//! Local server emulating the Advent of Code endpoints used by aoc-http-client
+//!
+//! For end-to-end tests and demos of the `aoc` CLI without touching
+//! adventofcode.com. Sessions, inputs, answers, puzzle text and leaderboards come
+//! from a config directory (see [`MockConfig`]); stars and throttle windows are
+//! kept in memory.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aoc_mock_server::{MockConfig, MockServer};
+//! use std::path::Path;
+//!
+//! # fn main() -> Result<(), aoc_mock_server::MockError> {
+//! let config = MockConfig::load(Path::new("aoc-mock-server/example"))?;
+//! let server = MockServer::bind("127.0.0.1:3000", config)?;
+//! println!("Run: aoc --base-url {}", server.url());
+//! server.serve();
+//! # Ok(())
+//! # }
+//! ```
+
+mod config;
+mod error;
+mod pages;
+mod server;
+
+pub use config::{DEFAULT_THROTTLE, MockConfig, MockPuzzle, SERVER_FILE};
+pub use error::MockError;
+pub use server::MockServer;
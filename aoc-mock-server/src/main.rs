@@ -0,0 +1,35 @@
+//! aoc-mock-server - serve a config directory as a local Advent of Code
+
+use aoc_mock_server::{MockConfig, MockServer};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Emulate the Advent of Code endpoints used by the `aoc` CLI
+#[derive(Parser, Debug)]
+#[command(name = "aoc-mock-server", version)]
+struct Args {
+    /// Directory holding server.json, inputs, answers and leaderboards
+    dir: PathBuf,
+
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    addr: String,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let server =
+        MockConfig::load(&args.dir).and_then(|config| MockServer::bind(&args.addr, config));
+    match server {
+        Ok(server) => {
+            println!("Serving {} at {}", args.dir.display(), server.url());
+            println!("Use it with: aoc --base-url {}", server.url());
+            server.serve();
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
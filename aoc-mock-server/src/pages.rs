@@ -0,0 +1,164 @@
+//! HTML pages in the shape aoc-http-client parses
+
+use crate::config::MockPuzzle;
+use std::time::Duration;
+
+/// Body of an input request without a valid session
+pub const INPUT_LOGIN: &str =
+    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+/// Body of a request for a puzzle that does not exist
+pub const NOT_FOUND: &str = "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>404 Not Found</title>\n</head>\n<body>\n<h1>404 Not Found</h1>\n</body>\n</html>\n";
+
+/// Wrap the contents of the main element in a page
+fn page(title: &str, main: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<meta charset=\"utf-8\"/>\n<title>{}</title>\n</head>\n<body>\n<main>\n{}\n</main>\n</body>\n</html>\n",
+        title, main
+    )
+}
+
+/// Wrap a submission message the way AoC does
+fn message(year: u16, day: u8, text: &str) -> String {
+    page(
+        &format!("Day {} - Advent of Code {}", day, year),
+        &format!(
+            "<article><p>{} <a href=\"/{}/day/{}\">[Return to Day {}]</a></p></article>",
+            text, year, day, day
+        ),
+    )
+}
+
+pub fn home() -> String {
+    page(
+        "Advent of Code",
+        "<article><p>aoc-mock-server</p></article>",
+    )
+}
+
+pub fn settings(user_id: u64) -> String {
+    page(
+        "Settings - Advent of Code",
+        &format!(
+            "<article><p>What would you like to be called?</p><p>(anonymous user #{})</p></article>",
+            user_id
+        ),
+    )
+}
+
+pub fn login() -> String {
+    page(
+        "Advent of Code",
+        "<article><p>To play, please identify yourself via one of these services:</p><p><a href=\"/auth/github\">[GitHub]</a></p></article>",
+    )
+}
+
+/// Calendar with one link per configured day, classed by stars collected
+pub fn calendar(year: u16, days: impl Iterator<Item = (u8, u8)>) -> String {
+    let links: String = days
+        .map(|(day, stars)| {
+            let class = match stars {
+                0 => String::new(),
+                1 => " calendar-complete".to_string(),
+                _ => " calendar-verycomplete".to_string(),
+            };
+            format!(
+                "<a aria-label=\"Day {}\" href=\"/{}/day/{}\" class=\"calendar-day{}{}\">Day {}</a>\n",
+                day, year, day, day, class, day
+            )
+        })
+        .collect();
+    page(
+        &format!("Advent of Code {}", year),
+        &format!("<pre class=\"calendar\">\n{}</pre>", links),
+    )
+}
+
+/// Puzzle page showing the parts unlocked by `stars` and the answers already given
+pub fn puzzle(year: u16, day: u8, puzzle: &MockPuzzle, stars: u8) -> String {
+    let mut main = String::new();
+    for (part, article) in puzzle.articles.iter().enumerate().take(stars as usize + 1) {
+        main.push_str(&format!(
+            "<article class=\"day-desc\">{}</article>\n",
+            article
+        ));
+        if part < stars as usize {
+            main.push_str(&format!(
+                "<p>Your puzzle answer was <code>{}</code>.</p>\n",
+                escape(&puzzle.answers[part])
+            ));
+        }
+    }
+    page(&format!("Day {} - Advent of Code {}", day, year), &main)
+}
+
+pub fn correct(year: u16, day: u8, last_part: bool) -> String {
+    let next = if last_part {
+        format!("You have completed Day {}!", day)
+    } else {
+        "[Continue to Part Two]".to_string()
+    };
+    message(
+        year,
+        day,
+        &format!(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. {}",
+            next
+        ),
+    )
+}
+
+pub fn incorrect(year: u16, day: u8, answer: &str, hint: Option<&str>) -> String {
+    let verdict = match hint {
+        Some(hint) => format!("That's not the right answer; your answer is too {}.", hint),
+        None => "That's not the right answer.".to_string(),
+    };
+    message(
+        year,
+        day,
+        &format!(
+            "{} (You guessed <code>{}</code>.) Please wait before trying again.",
+            verdict,
+            escape(answer)
+        ),
+    )
+}
+
+pub fn already_completed(year: u16, day: u8) -> String {
+    message(
+        year,
+        day,
+        "You don't seem to be solving the right level.  Did you already complete it?",
+    )
+}
+
+/// AoC sends the already-completed page for locked levels too; the mock drops
+/// the question so clients can tell the two cases apart in tests
+pub fn wrong_level(year: u16, day: u8) -> String {
+    message(year, day, "You don't seem to be solving the right level.")
+}
+
+pub fn throttled(year: u16, day: u8, wait: Duration) -> String {
+    // Round up so a client that waits exactly this long is no longer throttled
+    let secs = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    let left = if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    };
+    message(
+        year,
+        day,
+        &format!(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {} left to wait.",
+            left
+        ),
+    )
+}
+
+/// Escape text for use in HTML
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,391 @@
+//! Request routing and per-user puzzle progress
+
+use crate::config::MockConfig;
+use crate::error::MockError;
+use crate::pages;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+use tiny_http::{Header, Method, Request, Response};
+
+/// Stars and throttle windows per user, year and day
+#[derive(Default)]
+struct State {
+    stars: HashMap<(u64, u16, u8), u8>,
+    throttled_until: HashMap<(u64, u16, u8), Instant>,
+}
+
+/// A response before it is written
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+    location: Option<&'static str>,
+}
+
+impl Reply {
+    fn html(body: String) -> Self {
+        Self {
+            status: 200,
+            content_type: "text/html; charset=utf-8",
+            body,
+            location: None,
+        }
+    }
+
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.into(),
+            location: None,
+        }
+    }
+
+    fn json(body: String) -> Self {
+        Self {
+            status: 200,
+            content_type: "application/json",
+            body,
+            location: None,
+        }
+    }
+
+    fn not_found() -> Self {
+        Self {
+            status: 404,
+            ..Self::html(pages::NOT_FOUND.to_string())
+        }
+    }
+
+    /// Send users without a valid session to the home page, as AoC does
+    fn redirect_home() -> Self {
+        Self {
+            status: 302,
+            location: Some("/"),
+            ..Self::text(302, "")
+        }
+    }
+}
+
+/// HTTP server emulating the AoC endpoints used by aoc-http-client
+///
+/// Serves `/settings`, `/{year}`, `/{year}/day/{day}`, `/{year}/day/{day}/input`,
+/// `/{year}/day/{day}/answer` and `/{year}/leaderboard/private/view/{id}.json`.
+/// Progress starts empty and lives in memory: a correct answer earns a star and
+/// unlocks the next part, and a wrong one throttles that user's submissions for
+/// the day for [`MockConfig::throttle`].
+pub struct MockServer {
+    http: tiny_http::Server,
+    config: MockConfig,
+    state: Mutex<State>,
+}
+
+impl MockServer {
+    /// Listen on `addr` (e.g. `127.0.0.1:3000`, or port 0 for any free port)
+    pub fn bind(addr: &str, config: MockConfig) -> Result<Self, MockError> {
+        let http = tiny_http::Server::http(addr).map_err(|e| MockError::Bind {
+            addr: addr.to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(Self {
+            http,
+            config,
+            state: Mutex::default(),
+        })
+    }
+
+    /// Base URL to pass to `--base-url` or `AocClientBuilder::base_url`
+    pub fn url(&self) -> String {
+        format!("http://{}", self.http.server_addr())
+    }
+
+    /// Answer requests until the process exits
+    pub fn serve(&self) {
+        for request in self.http.incoming_requests() {
+            self.handle(request);
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let user = request
+            .headers()
+            .iter()
+            .filter(|h| h.field.equiv("Cookie"))
+            .flat_map(|h| h.value.as_str().split(';'))
+            .find_map(|c| c.trim().strip_prefix("session="))
+            .and_then(|session| self.config.sessions.get(session))
+            .copied();
+        let mut body = String::new();
+        // An unreadable body is treated as an empty form
+        request.as_reader().read_to_string(&mut body).ok();
+
+        let (path, _query) = request.url().split_once('?').unwrap_or((request.url(), ""));
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let reply = self.route(request.method(), &segments, user, &body);
+
+        let mut response = Response::from_string(reply.body)
+            .with_status_code(reply.status)
+            .with_header(header("Content-Type", reply.content_type));
+        if let Some(location) = reply.location {
+            response = response.with_header(header("Location", location));
+        }
+        // The client may have hung up; nothing to do about it
+        request.respond(response).ok();
+    }
+
+    fn route(&self, method: &Method, segments: &[&str], user: Option<u64>, body: &str) -> Reply {
+        match (method, segments) {
+            (Method::Get, []) => Reply::html(pages::home()),
+            (Method::Get, ["settings"]) => match user {
+                Some(user) => Reply::html(pages::settings(user)),
+                None => Reply::redirect_home(),
+            },
+            (Method::Get, [year]) => match year.parse() {
+                Ok(year) => self.calendar(year, user),
+                Err(_) => Reply::not_found(),
+            },
+            (Method::Get, [year, "day", day]) => match parse_day(year, day) {
+                Some((year, day)) => self.puzzle(year, day, user),
+                None => Reply::not_found(),
+            },
+            (Method::Get, [year, "day", day, "input"]) => match parse_day(year, day) {
+                Some((year, day)) => self.input(year, day, user),
+                None => Reply::not_found(),
+            },
+            (Method::Post, [year, "day", day, "answer"]) => match parse_day(year, day) {
+                Some((year, day)) => self.answer(year, day, user, body),
+                None => Reply::not_found(),
+            },
+            (Method::Get, [year, "leaderboard", "private", "view", file]) => {
+                let key = year
+                    .parse()
+                    .ok()
+                    .zip(file.strip_suffix(".json").and_then(|id| id.parse().ok()));
+                match (user, key.and_then(|key| self.config.leaderboards.get(&key))) {
+                    (None, _) => Reply::redirect_home(),
+                    (Some(_), Some(board)) => Reply::json(board.clone()),
+                    (Some(_), None) => Reply::not_found(),
+                }
+            }
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn stars(&self, user: Option<u64>, year: u16, day: u8) -> u8 {
+        user.and_then(|user| {
+            self.state
+                .lock()
+                .unwrap()
+                .stars
+                .get(&(user, year, day))
+                .copied()
+        })
+        .unwrap_or(0)
+    }
+
+    fn calendar(&self, year: u16, user: Option<u64>) -> Reply {
+        let days: Vec<(u8, u8)> = self
+            .config
+            .puzzles
+            .keys()
+            .filter(|(y, _)| *y == year)
+            .map(|&(_, day)| (day, self.stars(user, year, day)))
+            .collect();
+        if days.is_empty() {
+            return Reply::not_found();
+        }
+        Reply::html(pages::calendar(year, days.into_iter()))
+    }
+
+    fn puzzle(&self, year: u16, day: u8, user: Option<u64>) -> Reply {
+        match self.config.puzzles.get(&(year, day)) {
+            Some(puzzle) => Reply::html(pages::puzzle(
+                year,
+                day,
+                puzzle,
+                self.stars(user, year, day),
+            )),
+            None => Reply::not_found(),
+        }
+    }
+
+    fn input(&self, year: u16, day: u8, user: Option<u64>) -> Reply {
+        match (user, self.config.puzzles.get(&(year, day))) {
+            (None, _) => Reply::text(400, pages::INPUT_LOGIN),
+            (Some(_), Some(puzzle)) => Reply::text(200, puzzle.input.clone()),
+            (Some(_), None) => Reply::not_found(),
+        }
+    }
+
+    fn answer(&self, year: u16, day: u8, user: Option<u64>, body: &str) -> Reply {
+        let Some(user) = user else {
+            return Reply::html(pages::login());
+        };
+        let Some(puzzle) = self.config.puzzles.get(&(year, day)) else {
+            return Reply::not_found();
+        };
+        let form: HashMap<String, String> = form_urlencoded::parse(body.as_bytes())
+            .into_owned()
+            .collect();
+        let level: Option<u8> = form.get("level").and_then(|l| l.parse().ok());
+        let answer = form.get("answer").map(|a| a.trim()).unwrap_or_default();
+
+        let key = (user, year, day);
+        let mut state = self.state.lock().unwrap();
+        let stars = state.stars.get(&key).copied().unwrap_or(0);
+        let Some(level) =
+            level.filter(|&l| l == stars + 1 && usize::from(l) <= puzzle.answers.len())
+        else {
+            if level.is_some_and(|l| (1..=stars).contains(&l)) {
+                return Reply::html(pages::already_completed(year, day));
+            }
+            return Reply::html(pages::wrong_level(year, day));
+        };
+
+        let now = Instant::now();
+        if let Some(&until) = state.throttled_until.get(&key)
+            && until > now
+        {
+            return Reply::html(pages::throttled(year, day, until - now));
+        }
+
+        let expected = &puzzle.answers[usize::from(level) - 1];
+        if answer == expected {
+            state.stars.insert(key, level);
+            return Reply::html(pages::correct(
+                year,
+                day,
+                usize::from(level) == puzzle.answers.len(),
+            ));
+        }
+
+        state
+            .throttled_until
+            .insert(key, now + self.config.throttle);
+        let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+            (Ok(given), Ok(expected)) if given > expected => Some("high"),
+            (Ok(given), Ok(expected)) if given < expected => Some("low"),
+            _ => None,
+        };
+        Reply::html(pages::incorrect(year, day, answer, hint))
+    }
+}
+
+fn parse_day(year: &str, day: &str) -> Option<(u16, u8)> {
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn header(field: &str, value: &str) -> Header {
+    Header::from_bytes(field.as_bytes(), value.as_bytes()).expect("valid header")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_http_client::{AnswerHint, AocClient, AocError, SubmissionResult};
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    const SESSION: &str = "mock-session";
+
+    fn start(throttle: Duration) -> AocClient {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("example");
+        let mut config = MockConfig::load(&dir).unwrap();
+        config.throttle = throttle;
+        let server = Arc::new(MockServer::bind("127.0.0.1:0", config).unwrap());
+        let url = server.url();
+        std::thread::spawn(move || server.serve());
+        AocClient::builder("aoc-mock-server tests")
+            .base_url(&url)
+            .unwrap()
+            .max_retries(0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sessions_inputs_and_leaderboards() {
+        let client = start(Duration::from_secs(60));
+
+        assert_eq!(
+            client.verify_session(SESSION).unwrap().user_id,
+            Some(123456)
+        );
+        assert_eq!(client.verify_session("unknown").unwrap().user_id, None);
+
+        assert!(
+            client
+                .get_input(2023, 1, SESSION)
+                .unwrap()
+                .starts_with("1abc2")
+        );
+        assert!(matches!(
+            client.get_input(2023, 1, "unknown"),
+            Err(AocError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.get_input(2023, 2, SESSION),
+            Err(AocError::PuzzleNotFound)
+        ));
+
+        let board = client.get_private_leaderboard(2023, 7, SESSION).unwrap();
+        assert_eq!(board.owner_id, 123456);
+        assert!(client.get_private_leaderboard(2023, 8, SESSION).is_err());
+    }
+
+    #[test]
+    fn test_submissions_unlock_parts_and_throttle() {
+        let client = start(Duration::from_millis(300));
+
+        let puzzle = client.get_puzzle(2023, 1, SESSION).unwrap();
+        assert_eq!(puzzle.title, "Trebuchet?!");
+        assert_eq!(puzzle.parts.len(), 1);
+        assert_eq!(client.get_calendar(2023, SESSION).unwrap().days[&1], 0);
+
+        assert_eq!(
+            client.submit_answer(2023, 1, 2, "142", SESSION).unwrap(),
+            SubmissionResult::WrongLevel
+        );
+        assert_eq!(
+            client.submit_answer(2023, 1, 1, "100", SESSION).unwrap(),
+            SubmissionResult::Incorrect {
+                hint: Some(AnswerHint::TooLow)
+            }
+        );
+        assert!(matches!(
+            client.submit_answer(2023, 1, 1, "142", SESSION).unwrap(),
+            SubmissionResult::Throttled { wait_time: Some(_) }
+        ));
+        // Poll until the throttle from the wrong answer has run out
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let result = loop {
+            match client.submit_answer(2023, 1, 1, "142", SESSION).unwrap() {
+                SubmissionResult::Throttled { .. } if Instant::now() < deadline => {
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+                result => break result,
+            }
+        };
+        assert_eq!(result, SubmissionResult::Correct);
+        assert_eq!(
+            client.submit_answer(2023, 1, 1, "142", SESSION).unwrap(),
+            SubmissionResult::AlreadyCompleted
+        );
+        assert!(matches!(
+            client.submit_answer(2023, 1, 1, "142", "unknown"),
+            Err(AocError::SessionExpired)
+        ));
+
+        let puzzle = client.get_puzzle(2023, 1, SESSION).unwrap();
+        assert_eq!(puzzle.parts.len(), 2);
+        assert_eq!(puzzle.parts[0].answer.as_deref(), Some("142"));
+        assert_eq!(client.get_calendar(2023, SESSION).unwrap().days[&1], 1);
+        // Progress is per user
+        assert_eq!(
+            client.get_calendar(2023, "mock-session-2").unwrap().days[&1],
+            0
+        );
+    }
+}
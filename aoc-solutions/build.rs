@@ -0,0 +1,98 @@
+//! Generates the module tree for `src/yearYYYY/dayDD.rs` solutions
+//!
+//! Writes `$OUT_DIR/solutions.rs`, included by `lib.rs`, with one `yearYYYY`
+//! module per year directory. Each is compiled with the `yearYYYY` feature or
+//! `all-years` (the default), so a binary can build only the years it needs.
+//!
+//! A build script cannot declare features, so `yearYYYY` must be listed under
+//! `[features]` in `Cargo.toml` (`aoc new` adds it). Until it is, the year only
+//! builds with `all-years`, and a warning is printed.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set");
+    let src = Path::new(&manifest_dir).join("src");
+    println!("cargo:rerun-if-changed=src");
+
+    let manifest = fs::read_to_string(Path::new(&manifest_dir).join("Cargo.toml"))
+        .expect("failed to read Cargo.toml");
+
+    let mut modules = String::new();
+    let mut features = Vec::new();
+    for (year, dir) in numbered_entries(&src, "year", 4, "") {
+        let days = numbered_entries(&dir, "day", 2, ".rs");
+        if days.is_empty() {
+            continue;
+        }
+        let feature = format!("year{}", year);
+        if !declares_feature(&manifest, &feature) {
+            println!(
+                "cargo:warning=src/{feature} has no `{feature}` feature in Cargo.toml; \
+                 add `{feature} = []` under [features] to build it without `all-years`"
+            );
+        }
+        features.push(format!("{:?}", feature));
+        writeln!(
+            modules,
+            "#[cfg(any(feature = \"all-years\", feature = \"year{}\"))]",
+            year
+        )
+        .unwrap();
+        writeln!(modules, "pub mod year{} {{", year).unwrap();
+        for (day, path) in days {
+            writeln!(modules, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(modules, "    pub mod day{};", day).unwrap();
+        }
+        writeln!(modules, "}}").unwrap();
+    }
+
+    // Accept the cfgs of undeclared years too, so they are not reported as unexpected
+    println!(
+        "cargo:rustc-check-cfg=cfg(feature, values({}))",
+        features.join(", ")
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set");
+    fs::write(Path::new(&out_dir).join("solutions.rs"), modules)
+        .expect("failed to write generated modules");
+}
+
+/// Whether `manifest` has a `feature = ...` line, as `[features]` entries are written
+fn declares_feature(manifest: &str, feature: &str) -> bool {
+    manifest
+        .lines()
+        .any(|line| line.split_once('=').map(|(key, _)| key.trim()) == Some(feature))
+}
+
+/// Entries of `dir` named `{prefix}{digits}{suffix}`, as (digits, path) sorted by name
+///
+/// Directories are listed when `suffix` is empty, files otherwise.
+fn numbered_entries(
+    dir: &Path,
+    prefix: &str,
+    digits: usize,
+    suffix: &str,
+) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?.to_string();
+            let wanted = if suffix.is_empty() {
+                path.is_dir()
+            } else {
+                path.is_file()
+            };
+            (wanted && number.len() == digits && number.bytes().all(|b| b.is_ascii_digit()))
+                .then_some((number, path))
+        })
+        .collect();
+    found.sort();
+    found
+}
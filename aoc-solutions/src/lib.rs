@@ -3,5 +3,12 @@
 //! This crate contains actual puzzle solutions organized by year.
 //! Each solution uses the `AutoRegisterSolver` derive macro for automatic
 //! plugin registration with the solver framework.
+//!
+//! Solutions live in `src/yearYYYY/dayDD.rs`; `build.rs` declares them, so adding
+//! a day needs no `mod` lines. Each year is compiled when its `yearYYYY` feature
+//! or the default `all-years` feature is enabled.
 
 pub mod stress_test;
+
+// `pub mod yearYYYY { pub mod dayDD; ... }` for every solution file
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
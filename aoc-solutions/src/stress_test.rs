@@ -3,7 +3,7 @@
 //! These solvers are used to test parallelism without actual computation.
 //! Each solver sleeps for a deterministic duration based on year/day.
 
-use aoc_solver::{AocParser, ParseError, SolveError, Solver, SolverPlugin};
+use aoc_solver::{AocParser, Arena, ParseError, SendSolver, SolveError, Solver, SolverPlugin};
 use std::thread;
 use std::time::Duration;
 
@@ -28,7 +28,10 @@ macro_rules! stress_solver_many_parts {
         impl AocParser for $name {
             type SharedData<'a> = StressTestData;
 
-            fn parse(_input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+            fn parse<'a>(
+                _input: &'a str,
+                _arena: &'a Arena,
+            ) -> Result<Self::SharedData<'a>, ParseError> {
                 // Deterministic sleep duration based on year/day: 10-100ms
                 let sleep_ms = 10 + (($year as u64 * 25 + $day as u64) % 91);
                 Ok(StressTestData { sleep_ms })
@@ -53,7 +56,7 @@ macro_rules! stress_solver_many_parts {
             SolverPlugin {
                 year: $year,
                 day: $day,
-                solver: &$name,
+                solver: &SendSolver::<$name>::new(),
                 tags: &["stress-test"],
             }
         }
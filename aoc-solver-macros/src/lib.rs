@@ -14,6 +14,8 @@ use syn::{DeriveInput, Lit, parse_macro_input};
 /// - `year`: Required. The Advent of Code year (e.g., 2023)
 /// - `day`: Required. The day number (1-25)
 /// - `tags`: Optional. Array of string literals for filtering (e.g., ["easy", "parsing"])
+/// - `send`: Optional flag. Also registers owned `Send` instances so the solver can be
+///   created with `SolverRegistry::create_owned_solver`. Requires `SharedData: Send`.
 ///
 /// # Requirements
 ///
@@ -61,6 +63,7 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
     let mut year: Option<u16> = None;
     let mut day: Option<u8> = None;
     let mut tags: Vec<String> = Vec::new();
+    let mut send = false;
 
     // Parse nested meta items
     aoc_attr
@@ -90,6 +93,8 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
                         let _: syn::Token![,] = content.parse()?;
                     }
                 }
+            } else if meta.path.is_ident("send") {
+                send = true;
             }
             Ok(())
         })
@@ -106,6 +111,13 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
         quote! { &[#(#tag_strs),*] }
     };
 
+    // Owned-capable solvers register through the SendSolver adapter
+    let solver_ref = if send {
+        quote! { &::aoc_solver::SendSolver::<#name>::new() }
+    } else {
+        quote! { &#name }
+    };
+
     // Generate the code with a compile-time trait bound check
     let expanded = quote! {
         // Compile-time check that the type implements Solver trait
@@ -120,7 +132,7 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
             ::aoc_solver::SolverPlugin {
                 year: #year,
                 day: #day,
-                solver: &#name,
+                solver: #solver_ref,
                 tags: #tags_array,
             }
         }
@@ -132,8 +144,9 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
 /// Derive macro for generating Solver trait implementation from AocParser and PartSolver traits
 ///
 /// This macro generates the `Solver` trait implementation by dispatching `solve_part`
-/// to the appropriate `PartSolver<N>::solve`. Since `Solver: AocParser`, the macro
-/// only generates `PARTS` and `solve_part()` - `SharedData` and `parse()` are inherited.
+/// to the appropriate `PartSolver<N>::solve` and `validate_answer` to
+/// `PartSolver<N>::validate`. Since `Solver: AocParser`, the macro only generates
+/// `PARTS`, `solve_part()` and `validate_answer()` - `SharedData` and `parse()` are inherited.
 ///
 /// # Attributes
 ///
@@ -148,7 +161,7 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
 /// # Example
 ///
 /// ```ignore
-/// use aoc_solver::{AocParser, PartSolver, ParseError, SolveError};
+/// use aoc_solver::{AocParser, Arena, PartSolver, ParseError, SolveError};
 /// use aoc_solver_macros::AocSolver;
 ///
 /// #[derive(AocSolver)]
@@ -158,7 +171,7 @@ pub fn derive_auto_register_solver(input: TokenStream) -> TokenStream {
 /// impl AocParser for Day1 {
 ///     type SharedData<'a> = Vec<i32>;
 ///
-///     fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         input
 ///             .lines()
 ///             .map(|l| l.parse().map_err(|_| ParseError::InvalidFormat("bad int".into())))
@@ -221,6 +234,14 @@ pub fn derive_aoc_solver(input: TokenStream) -> TokenStream {
         })
         .collect();
 
+    let validate_arms: Vec<_> = (1..=max_parts)
+        .map(|n| {
+            quote! {
+                #n => <Self as ::aoc_solver::PartSolver<#n>>::validate(answer),
+            }
+        })
+        .collect();
+
     // Generate the Solver trait implementation
     // Since Solver: AocParser, we only need to generate PARTS and solve_part()
     // SharedData and parse() are inherited from AocParser
@@ -237,6 +258,16 @@ pub fn derive_aoc_solver(input: TokenStream) -> TokenStream {
                     _ => Err(::aoc_solver::SolveError::PartNotImplemented(part)),
                 }
             }
+
+            fn validate_answer(
+                part: u8,
+                answer: &str,
+            ) -> Result<(), ::aoc_solver::AnswerValidationError> {
+                match part {
+                    #(#validate_arms)*
+                    _ => ::aoc_solver::check_answer_shape(answer),
+                }
+            }
         }
     };
 
@@ -1,5 +1,5 @@
 use aoc_solver::{
-    AocParser, AocSolver, AutoRegisterSolver, ParseError, PartSolver, SolveError, Solver,
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError, Solver,
     SolverRegistryBuilder,
 };
 
@@ -10,7 +10,7 @@ struct TestSolver1;
 impl AocParser for TestSolver1 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -38,7 +38,8 @@ impl PartSolver<2> for TestSolver1 {
 #[test]
 fn test_aoc_solver_with_manual_registration() {
     let input = "2\n3\n4";
-    let mut shared = <TestSolver1 as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <TestSolver1 as AocParser>::parse(input, &arena).unwrap();
     assert_eq!(shared, vec![2, 3, 4]);
 
     // Test Solver trait is implemented
@@ -80,7 +81,7 @@ struct CombinedMacroSolver;
 impl AocParser for CombinedMacroSolver {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -108,7 +109,8 @@ impl PartSolver<2> for CombinedMacroSolver {
 fn test_both_macros_work_together() {
     // Test that the Solver trait is implemented
     let input = "5\n6\n7";
-    let mut shared = <CombinedMacroSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <CombinedMacroSolver as AocParser>::parse(input, &arena).unwrap();
     assert_eq!(shared, vec![5, 6, 7]);
 
     let result = <CombinedMacroSolver as Solver>::solve_part(&mut shared, 1).unwrap();
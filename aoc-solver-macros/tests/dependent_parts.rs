@@ -1,4 +1,4 @@
-use aoc_solver::{AocParser, AocSolver, ParseError, PartSolver, SolveError, Solver};
+use aoc_solver::{AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, Solver};
 
 #[derive(Debug, Clone)]
 struct SharedData {
@@ -14,7 +14,7 @@ struct TestDependentSolver;
 impl AocParser for TestDependentSolver {
     type SharedData<'a> = SharedData;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         let numbers: Vec<i32> = input
             .lines()
             .map(|line| {
@@ -64,14 +64,16 @@ impl PartSolver<2> for TestDependentSolver {
 fn test_dependent_parts_compiles() {
     // Test that the macro generates valid code
     let input = "10\n20\n30";
-    let shared = <TestDependentSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let shared = <TestDependentSolver as AocParser>::parse(input, &arena).unwrap();
     assert_eq!(shared.numbers, vec![10, 20, 30]);
 }
 
 #[test]
 fn test_part1_stores_data() {
     let input = "10\n20\n30";
-    let mut shared = <TestDependentSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <TestDependentSolver as AocParser>::parse(input, &arena).unwrap();
 
     let result = <TestDependentSolver as Solver>::solve_part(&mut shared, 1).unwrap();
     assert_eq!(result, "60");
@@ -84,7 +86,8 @@ fn test_part1_stores_data() {
 #[test]
 fn test_part2_uses_part1_data() {
     let input = "10\n20\n30";
-    let mut shared = <TestDependentSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <TestDependentSolver as AocParser>::parse(input, &arena).unwrap();
 
     // First solve Part 1 to populate shared data
     let _part1_result = <TestDependentSolver as Solver>::solve_part(&mut shared, 1).unwrap();
@@ -99,7 +102,8 @@ fn test_part2_uses_part1_data() {
 #[test]
 fn test_part2_solves_independently() {
     let input = "10\n20\n30";
-    let mut shared = <TestDependentSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <TestDependentSolver as AocParser>::parse(input, &arena).unwrap();
 
     // Solve Part 2 without Part 1 (shared.sum and shared.count are None)
     let result = <TestDependentSolver as Solver>::solve_part(&mut shared, 2).unwrap();
@@ -1,4 +1,4 @@
-use aoc_solver::{AocParser, AocSolver, ParseError, PartSolver, SolveError, Solver};
+use aoc_solver::{AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, Solver};
 
 #[derive(AocSolver)]
 #[aoc_solver(max_parts = 2)]
@@ -7,7 +7,7 @@ struct TestSolver;
 impl AocParser for TestSolver {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -35,7 +35,8 @@ impl PartSolver<2> for TestSolver {
 fn test_independent_parts_compiles() {
     // Test that the macro generates valid code
     let input = "1\n2\n3\n4\n5";
-    let shared = <TestSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let shared = <TestSolver as AocParser>::parse(input, &arena).unwrap();
     assert_eq!(shared, vec![1, 2, 3, 4, 5]);
 }
 
@@ -43,7 +44,8 @@ fn test_independent_parts_compiles() {
 fn test_solver_trait_implemented() {
     // Test that Solver trait is implemented
     let input = "1\n2\n3";
-    let mut shared = <TestSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <TestSolver as AocParser>::parse(input, &arena).unwrap();
 
     let result1 = <TestSolver as Solver>::solve_part(&mut shared, 1).unwrap();
     assert_eq!(result1, "6");
@@ -55,7 +57,8 @@ fn test_solver_trait_implemented() {
 #[test]
 fn test_part_out_of_range() {
     let input = "1\n2\n3";
-    let mut shared = <TestSolver as AocParser>::parse(input).unwrap();
+    let arena = Arena::new();
+    let mut shared = <TestSolver as AocParser>::parse(input, &arena).unwrap();
 
     let result = <TestSolver as Solver>::solve_part(&mut shared, 3);
     assert!(result.is_err());
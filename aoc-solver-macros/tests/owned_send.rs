@@ -0,0 +1,114 @@
+use aoc_solver::{
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError,
+    SolverError, SolverRegistryBuilder,
+};
+use std::sync::Arc;
+
+#[derive(AocSolver, AutoRegisterSolver)]
+#[aoc_solver(max_parts = 2)]
+#[aoc(year = 2022, day = 3, tags = ["owned"], send)]
+struct BorrowingSolver;
+
+impl AocParser for BorrowingSolver {
+    // Borrows lines from the input to check the owned instance keeps it alive
+    type SharedData<'a> = Vec<&'a str>;
+
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        Ok(input.lines().collect())
+    }
+}
+
+impl PartSolver<1> for BorrowingSolver {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.len().to_string())
+    }
+}
+
+impl PartSolver<2> for BorrowingSolver {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.concat())
+    }
+}
+
+#[derive(AocSolver, AutoRegisterSolver)]
+#[aoc_solver(max_parts = 1)]
+#[aoc(year = 2022, day = 4, tags = ["owned"])]
+struct PlainSolver;
+
+impl AocParser for PlainSolver {
+    type SharedData<'a> = ();
+
+    fn parse<'a>(_input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        Ok(())
+    }
+}
+
+impl PartSolver<1> for PlainSolver {
+    fn solve(_shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok("plain".to_string())
+    }
+}
+
+fn registry() -> aoc_solver::SolverRegistry {
+    SolverRegistryBuilder::new()
+        .register_solver_plugins(|plugin| plugin.tags.contains(&"owned"))
+        .unwrap()
+        .build()
+}
+
+#[test]
+fn test_owned_solver_outlives_input_and_moves_across_threads() {
+    let registry = registry();
+
+    let mut solver = {
+        let input = String::from("ab\ncd\nef");
+        registry.create_owned_solver(2022, 3, input).unwrap()
+    };
+
+    let handle = std::thread::spawn(move || {
+        let part1 = solver.solve(1).unwrap().answer;
+        let part2 = solver.solve(2).unwrap().answer;
+        (part1, part2)
+    });
+
+    assert_eq!(
+        handle.join().unwrap(),
+        ("3".to_string(), "abcdef".to_string())
+    );
+}
+
+#[test]
+fn test_owned_solver_shares_arc_input() {
+    let registry = registry();
+    let input: Arc<str> = Arc::from("x\ny");
+
+    let mut solver = registry
+        .create_owned_solver(2022, 3, input.clone())
+        .unwrap();
+
+    assert_eq!(Arc::strong_count(&input), 2);
+    assert_eq!(solver.solve(1).unwrap().answer, "2");
+    drop(solver);
+    assert_eq!(Arc::strong_count(&input), 1);
+}
+
+#[test]
+fn test_send_solver_still_supports_borrowed_instances() {
+    let registry = registry();
+
+    let mut solver = registry.create_solver(2022, 3, "a\nb").unwrap();
+
+    assert_eq!(solver.solve(2).unwrap().answer, "ab");
+}
+
+#[test]
+fn test_owned_solver_requires_send_registration() {
+    let registry = registry();
+
+    let result = registry.create_owned_solver(2022, 4, "");
+
+    assert!(matches!(
+        result,
+        Err(SolverError::OwnedNotSupported(2022, 4))
+    ));
+}
@@ -1,4 +1,4 @@
-use aoc_solver::{AocParser, AocSolver, ParseError, PartSolver, SolveError, Solver};
+use aoc_solver::{AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, Solver};
 
 #[derive(Debug, Clone)]
 struct SharedData {
@@ -13,7 +13,7 @@ struct TestResultReturns;
 impl AocParser for TestResultReturns {
     type SharedData<'a> = SharedData;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         let numbers: Vec<i32> = input
             .lines()
             .map(|line| {
@@ -7,7 +7,7 @@
 //! Run with: cargo run --example auto_register_macro
 
 use aoc_solver::{
-    AocParser, AocSolver, AutoRegisterSolver, ParseError, PartSolver, SolveError,
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError,
     SolverRegistryBuilder,
 };
 
@@ -20,7 +20,7 @@ struct Day1;
 impl AocParser for Day1 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -53,7 +53,7 @@ struct Day2;
 impl AocParser for Day2 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
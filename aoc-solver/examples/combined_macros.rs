@@ -5,7 +5,9 @@
 //!
 //! Run with: cargo run --example combined_macros
 
-use aoc_solver::{AocParser, AocSolver, ParseError, PartSolver, SolveError, SolverRegistryBuilder};
+use aoc_solver::{
+    AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, SolverRegistryBuilder,
+};
 
 /// Example solver using the macro
 #[derive(AocSolver)]
@@ -15,7 +17,7 @@ struct Day1;
 impl AocParser for Day1 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -57,7 +59,7 @@ struct Day2;
 impl AocParser for Day2 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
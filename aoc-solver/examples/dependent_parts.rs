@@ -7,7 +7,7 @@
 //! Run with: cargo run --example dependent_parts
 
 use aoc_solver::{
-    AocParser, AocSolver, AutoRegisterSolver, ParseError, PartSolver, SolveError,
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError,
     SolverRegistryBuilder,
 };
 
@@ -31,7 +31,7 @@ pub struct ExampleDependent;
 impl AocParser for ExampleDependent {
     type SharedData<'a> = SharedData;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         let numbers: Vec<i32> = input
             .lines()
             .map(|line| {
@@ -136,7 +136,8 @@ mod tests {
     #[test]
     fn test_parse_valid_input() {
         let input = "10\n20\n30";
-        let shared = <ExampleDependent as AocParser>::parse(input).unwrap();
+        let arena = Arena::new();
+        let shared = <ExampleDependent as AocParser>::parse(input, &arena).unwrap();
         assert_eq!(shared.numbers, vec![10, 20, 30]);
     }
 
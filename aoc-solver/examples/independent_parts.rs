@@ -6,7 +6,7 @@
 //! Run with: cargo run --example independent_parts
 
 use aoc_solver::{
-    AocParser, AocSolver, AutoRegisterSolver, ParseError, PartSolver, SolveError,
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError,
     SolverRegistryBuilder,
 };
 
@@ -22,7 +22,7 @@ pub struct ExampleIndependent;
 impl AocParser for ExampleIndependent {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -95,21 +95,24 @@ mod tests {
     #[test]
     fn test_parse_valid_input() {
         let input = "1\n2\n3\n4\n5";
-        let shared = ExampleIndependent::parse(input).unwrap();
+        let arena = Arena::new();
+        let shared = ExampleIndependent::parse(input, &arena).unwrap();
         assert_eq!(shared, vec![1, 2, 3, 4, 5]);
     }
 
     #[test]
     fn test_parse_with_whitespace() {
         let input = "  1  \n  2  \n  3  ";
-        let shared = ExampleIndependent::parse(input).unwrap();
+        let arena = Arena::new();
+        let shared = ExampleIndependent::parse(input, &arena).unwrap();
         assert_eq!(shared, vec![1, 2, 3]);
     }
 
     #[test]
     fn test_parse_invalid_input() {
         let input = "1\nabc\n3";
-        let result = ExampleIndependent::parse(input);
+        let arena = Arena::new();
+        let result = ExampleIndependent::parse(input, &arena);
         assert!(result.is_err());
     }
 
@@ -6,7 +6,7 @@
 //! Run with: cargo run --example macro_usage
 
 use aoc_solver::{
-    AocParser, AocSolver, AutoRegisterSolver, ParseError, PartSolver, SolveError, Solver,
+    AocParser, AocSolver, Arena, AutoRegisterSolver, ParseError, PartSolver, SolveError, Solver,
     SolverRegistryBuilder,
 };
 
@@ -18,7 +18,7 @@ struct SimpleExample;
 impl AocParser for SimpleExample {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -58,7 +58,7 @@ struct DependentExample;
 impl AocParser for DependentExample {
     type SharedData<'a> = SharedData;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         let numbers: Vec<i32> = input
             .lines()
             .map(|line| {
@@ -131,7 +131,7 @@ impl AocParser for ZeroCopyStrExample {
     // Using `&'a str` as SharedData - no allocation, just borrow the input!
     type SharedData<'a> = &'a str;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         // Zero-copy: just return a borrowed reference to the input
         Ok(input)
     }
@@ -159,7 +159,8 @@ fn main() {
     let input1 = "1\n2\n3\n4\n5";
     println!("Input: {}", input1.replace('\n', ", "));
 
-    let mut shared1 = <SimpleExample as AocParser>::parse(input1).expect("Failed to parse");
+    let arena = Arena::new();
+    let mut shared1 = <SimpleExample as AocParser>::parse(input1, &arena).expect("Failed to parse");
     println!(
         "Part 1 (Sum): {}",
         <SimpleExample as Solver>::solve_part(&mut shared1, 1).unwrap()
@@ -174,7 +175,9 @@ fn main() {
     let input2 = "10\n20\n30\n40\n50";
     println!("Input: {}", input2.replace('\n', ", "));
 
-    let mut shared2 = <DependentExample as AocParser>::parse(input2).expect("Failed to parse");
+    let arena = Arena::new();
+    let mut shared2 =
+        <DependentExample as AocParser>::parse(input2, &arena).expect("Failed to parse");
 
     // Part 1 stores data
     let part1_answer = <DependentExample as Solver>::solve_part(&mut shared2, 1).unwrap();
@@ -187,7 +190,8 @@ fn main() {
     println!("\n=== Using PartSolver Traits Directly ===\n");
 
     let input3 = "2\n4\n6";
-    let mut shared3 = <SimpleExample as AocParser>::parse(input3).expect("Failed to parse");
+    let arena = Arena::new();
+    let mut shared3 = <SimpleExample as AocParser>::parse(input3, &arena).expect("Failed to parse");
 
     let result1 =
         <SimpleExample as PartSolver<1>>::solve(&mut shared3).expect("Failed to solve part 1");
@@ -209,7 +213,9 @@ fn main() {
     println!("Input: {:?}", input4);
 
     // Direct usage via traits
-    let mut shared4 = <ZeroCopyStrExample as AocParser>::parse(input4).expect("Failed to parse");
+    let arena = Arena::new();
+    let mut shared4 =
+        <ZeroCopyStrExample as AocParser>::parse(input4, &arena).expect("Failed to parse");
 
     // With &str as SharedData, it's always zero-copy (just a reference)
     println!("Zero-copy: true (using &str directly)");
@@ -6,7 +6,7 @@
 //! Run with: cargo run --example plugin_system
 
 use aoc_solver::{
-    AocParser, AutoRegisterSolver, ParseError, SolveError, Solver, SolverPlugin,
+    AocParser, Arena, AutoRegisterSolver, ParseError, SolveError, Solver, SolverPlugin,
     SolverRegistryBuilder,
 };
 
@@ -22,7 +22,7 @@ pub struct PluginDay1;
 impl AocParser for PluginDay1 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -55,7 +55,7 @@ pub struct PluginDay2;
 impl AocParser for PluginDay2 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -98,7 +98,7 @@ pub struct PluginDay3;
 impl AocParser for PluginDay3 {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -145,7 +145,7 @@ pub struct PluginDay4Derive;
 impl AocParser for PluginDay4Derive {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .map(|line| {
@@ -0,0 +1,136 @@
+//! Bump arena owned by a solver instance
+//!
+//! Parsers that build large linked structures can allocate nodes in the
+//! instance's [`Arena`] through [`AocParser::parse`](crate::AocParser::parse)
+//! and link them with plain `&'a Node` references. The whole arena is freed at
+//! once when the solver instance drops.
+
+use bumpalo::Bump;
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+/// Allocation statistics for an [`Arena`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArenaStats {
+    /// Number of allocations made
+    pub allocations: usize,
+    /// Total bytes requested by those allocations
+    pub bytes: usize,
+}
+
+/// Bump allocator handed to parsers, with allocation counting
+///
+/// Values allocated in the arena are never dropped individually: their memory is
+/// released when the arena is, and their `Drop` implementations do not run. Keep
+/// types that own heap memory (e.g. `Vec`, `String`) out of the arena, or accept
+/// that their buffers are leaked.
+///
+/// # Example
+///
+/// ```
+/// use aoc_solver::Arena;
+///
+/// let arena = Arena::new();
+/// let a = arena.alloc(1u64);
+/// let name = arena.alloc_str("node");
+/// *a += 1;
+///
+/// assert_eq!((*a, &*name), (2, "node"));
+/// assert_eq!(arena.stats().allocations, 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct Arena {
+    bump: Bump,
+    allocations: Cell<usize>,
+    bytes: Cell<usize>,
+}
+
+impl Arena {
+    /// Create an empty arena
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocate a value in the arena
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        self.record(size_of::<T>());
+        self.bump.alloc(value)
+    }
+
+    /// Copy a string into the arena
+    pub fn alloc_str(&self, s: &str) -> &mut str {
+        self.record(s.len());
+        self.bump.alloc_str(s)
+    }
+
+    /// Copy a slice of `Copy` values into the arena
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        self.record(size_of_val(src));
+        self.bump.alloc_slice_copy(src)
+    }
+
+    /// Allocate a slice filled from an exact-size iterator
+    pub fn alloc_slice_fill_iter<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        self.record(size_of::<T>() * iter.len());
+        self.bump.alloc_slice_fill_iter(iter)
+    }
+
+    /// Get allocation statistics so far
+    pub fn stats(&self) -> ArenaStats {
+        ArenaStats {
+            allocations: self.allocations.get(),
+            bytes: self.bytes.get(),
+        }
+    }
+
+    fn record(&self, bytes: usize) {
+        self.allocations.set(self.allocations.get() + 1);
+        self.bytes.set(self.bytes.get() + bytes);
+    }
+}
+
+/// Owning pointer to a heap-allocated arena
+///
+/// Unlike `Box<Arena>`, moving an `ArenaBox` does not assert unique access to the
+/// arena, so it can be moved while shared data still borrows from it. Owners must
+/// drop every borrow before the `ArenaBox` itself.
+pub(crate) struct ArenaBox(NonNull<Arena>);
+
+impl ArenaBox {
+    /// Allocate a new empty arena
+    pub(crate) fn new() -> Self {
+        Self(NonNull::from(Box::leak(Box::new(Arena::new()))))
+    }
+
+    /// Borrow the arena for an unbounded lifetime
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the returned reference (and anything borrowing from
+    /// it) is not used after this `ArenaBox` is dropped.
+    pub(crate) unsafe fn get<'a>(&self) -> &'a Arena {
+        // SAFETY: the pointer comes from a live Box; the caller upholds the lifetime
+        unsafe { self.0.as_ref() }
+    }
+
+    /// Get allocation statistics so far
+    pub(crate) fn stats(&self) -> ArenaStats {
+        // SAFETY: the reference does not outlive `self`
+        unsafe { self.get() }.stats()
+    }
+}
+
+impl Drop for ArenaBox {
+    fn drop(&mut self) {
+        // SAFETY: created from Box::leak in `new` and freed exactly once here
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+// SAFETY: the ArenaBox uniquely owns its Arena, which is Send
+unsafe impl Send for ArenaBox {}
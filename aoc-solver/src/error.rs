@@ -30,6 +30,32 @@ pub enum SolveError {
     SolveFailed(#[source] Box<dyn std::error::Error + Send + Sync>),
 }
 
+/// Error type for answers rejected before submission
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AnswerValidationError {
+    /// The answer is an empty string
+    #[error("answer is empty")]
+    Empty,
+    /// The answer contains spaces, tabs or newlines
+    #[error("answer contains whitespace")]
+    ContainsWhitespace,
+    /// The answer contains a character never found in puzzle answers
+    #[error("answer contains unexpected character {0:?}")]
+    InvalidCharacter(char),
+    /// The answer is longer than any puzzle answer
+    #[error("answer is too long ({0} bytes)")]
+    TooLong(usize),
+    /// The answer is zero
+    #[error("answer is zero")]
+    Zero,
+    /// The answer is a negative number
+    #[error("answer is negative: {0}")]
+    Negative(String),
+    /// Rejected by a solver-specific check
+    #[error("{0}")]
+    Other(String),
+}
+
 /// Error type for solver operations
 #[derive(Debug, Error)]
 pub enum SolverError {
@@ -39,6 +65,9 @@ pub enum SolverError {
     /// Invalid year/day combination (out of supported range)
     #[error("Invalid year/day: year {0} day {1} (valid: 2015-2034, days 1-25)")]
     InvalidYearDay(u16, u8),
+    /// Solver was not registered with owned-instance support
+    #[error("Solver for year {0} day {1} does not support owned instances")]
+    OwnedNotSupported(u16, u8),
     /// Error occurred during parsing
     #[error("Parse error: {0}")]
     ParseError(#[from] ParseError),
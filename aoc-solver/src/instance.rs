@@ -1,24 +1,41 @@
 //! Solver instance implementation
 
-use crate::error::{ParseError, SolveError};
+use crate::arena::{ArenaBox, ArenaStats};
+use crate::error::{AnswerValidationError, ParseError, SolveError};
 use crate::solver::{Solver, SolverExt};
+use crate::timing::Timing;
+use crate::validation::check_answer_shape;
 use chrono::{DateTime, TimeDelta, Utc};
+use std::sync::Arc;
 
 /// Result from solving a puzzle part, including timing information
 #[derive(Debug, Clone)]
 pub struct SolveResult {
     /// The answer string
     pub answer: String,
-    /// When solving started (UTC)
-    pub solve_start: DateTime<Utc>,
-    /// When solving completed (UTC)
-    pub solve_end: DateTime<Utc>,
+    /// Solve timing (monotonic duration, wall-clock timestamps, CPU time)
+    pub timing: Timing,
 }
 
 impl SolveResult {
-    /// Get the solve duration as TimeDelta
+    /// Get the monotonic solve duration as TimeDelta
     pub fn duration(&self) -> TimeDelta {
-        self.solve_end - self.solve_start
+        self.timing.elapsed
+    }
+
+    /// Get the CPU time spent solving, if measurable on this platform
+    pub fn cpu_duration(&self) -> Option<TimeDelta> {
+        self.timing.cpu_time
+    }
+
+    /// When solving started (wall clock, UTC)
+    pub fn solve_start(&self) -> DateTime<Utc> {
+        self.timing.started_at
+    }
+
+    /// When solving completed (wall clock, UTC)
+    pub fn solve_end(&self) -> DateTime<Utc> {
+        self.timing.finished_at
     }
 }
 
@@ -26,13 +43,16 @@ impl SolveResult {
 ///
 /// Manages the state for solving a specific year-day problem, including:
 /// - The shared data (parsed input and intermediate results)
-/// - Parse timing information (start and end timestamps)
+/// - Parse timing information (monotonic duration and wall-clock timestamps)
+/// - The bump [`Arena`](crate::Arena) that parsing may allocate in, freed with the instance
 pub struct SolverInstance<'a, S: Solver> {
     year: u16,
     day: u8,
+    // Declared before `arena` so it is dropped first: it may borrow from the arena
     shared: S::SharedData<'a>,
-    parse_start: DateTime<Utc>,
-    parse_end: DateTime<Utc>,
+    arena: ArenaBox,
+    parse_timing: Timing,
+    parse_arena_stats: ArenaStats,
 }
 
 impl<'a, S: Solver> SolverInstance<'a, S> {
@@ -49,20 +69,97 @@ impl<'a, S: Solver> SolverInstance<'a, S> {
     /// * `Ok(SolverInstance)` - Successfully parsed and created instance with timing
     /// * `Err(ParseError)` - Parsing failed
     pub fn new(year: u16, day: u8, input: &'a str) -> Result<Self, ParseError> {
-        let parse_start = Utc::now();
-        let shared = S::parse(input)?;
-        let parse_end = Utc::now();
+        Self::parse_with(year, day, |arena| S::parse(input, arena))
+    }
+
+    /// Create a new solver instance by parsing raw input bytes
+    ///
+    /// Uses [`AocParser::parse_bytes`], so solvers implementing
+    /// [`AocBytesParser`](crate::AocBytesParser) parse without UTF-8 validation.
+    /// Records parse timing internally.
+    ///
+    /// # Arguments
+    /// * `year` - The Advent of Code year
+    /// * `day` - The day number (1-25)
+    /// * `input` - The raw input bytes to parse (e.g. a memory-mapped file)
+    ///
+    /// # Returns
+    /// * `Ok(SolverInstance)` - Successfully parsed and created instance with timing
+    /// * `Err(ParseError)` - Parsing failed
+    pub fn from_bytes(year: u16, day: u8, input: &'a [u8]) -> Result<Self, ParseError> {
+        Self::parse_with(year, day, |arena| S::parse_bytes(input, arena))
+    }
+
+    /// Get the current allocation statistics of this instance's arena
+    pub fn arena_stats(&self) -> ArenaStats {
+        self.arena.stats()
+    }
+
+    /// Allocate the arena and run `parse` against it with timing
+    fn parse_with(
+        year: u16,
+        day: u8,
+        parse: impl FnOnce(&'a crate::Arena) -> Result<S::SharedData<'a>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let arena = ArenaBox::new();
+        // SAFETY: the arena is owned by the returned instance, which drops `shared`
+        // (the only holder of borrows from it) before `arena`. The shared data is only
+        // handed to solvers as `&mut SharedData<'_>`, so the borrows cannot escape.
+        let arena_ref = unsafe { arena.get() };
+        let (shared, parse_timing) = Timing::measure(|| parse(arena_ref));
+        let shared = shared?;
+        let parse_arena_stats = arena.stats();
 
         Ok(Self {
             year,
             day,
             shared,
-            parse_start,
-            parse_end,
+            arena,
+            parse_timing,
+            parse_arena_stats,
         })
     }
 }
 
+/// A solver instance that owns its input
+///
+/// Unlike [`SolverInstance`], which borrows the input for `'a`, this type keeps the
+/// input alive in an `Arc<str>` so the instance is `'static`. It can be stored across
+/// a REPL or server session, and it is `Send` whenever the solver's `SharedData` is.
+pub struct OwnedSolverInstance<S: Solver> {
+    // Declared before `input` so it is dropped first: the shared data may borrow from it
+    instance: SolverInstance<'static, S>,
+    input: Arc<str>,
+}
+
+impl<S: Solver> OwnedSolverInstance<S> {
+    /// Create a new owned solver instance by parsing input
+    ///
+    /// # Arguments
+    /// * `year` - The Advent of Code year
+    /// * `day` - The day number (1-25)
+    /// * `input` - The raw input, shared with the instance
+    ///
+    /// # Returns
+    /// * `Ok(OwnedSolverInstance)` - Successfully parsed and created instance with timing
+    /// * `Err(ParseError)` - Parsing failed
+    pub fn new(year: u16, day: u8, input: impl Into<Arc<str>>) -> Result<Self, ParseError> {
+        let input: Arc<str> = input.into();
+        // SAFETY: the string lives in the Arc allocation, which never moves and is kept
+        // alive by `self.input` until after `instance` is dropped. The shared data is only
+        // ever handed to solvers as `&mut SharedData<'_>`, so the extended lifetime cannot
+        // escape the instance.
+        let borrowed: &'static str = unsafe { &*Arc::as_ptr(&input) };
+        let instance = SolverInstance::new(year, day, borrowed)?;
+        Ok(Self { instance, input })
+    }
+
+    /// Get the input this instance was parsed from
+    pub fn input(&self) -> &Arc<str> {
+        &self.input
+    }
+}
+
 /// Type-erased interface for working with any solver through dynamic dispatch
 ///
 /// This trait provides a uniform interface for interacting with different solver types.
@@ -100,11 +197,22 @@ pub trait DynSolver {
     /// * `Err(SolveError)` - The part is not implemented or solving failed
     fn solve(&mut self, part: u8) -> Result<SolveResult, SolveError>;
 
-    /// Get the parse start time (UTC)
-    fn parse_start(&self) -> DateTime<Utc>;
+    /// Get the parse timing
+    fn parse_timing(&self) -> Timing;
 
-    /// Get the parse end time (UTC)
-    fn parse_end(&self) -> DateTime<Utc>;
+    /// Check an answer for a part before it is submitted
+    ///
+    /// The default runs [`check_answer_shape`]; solver instances delegate to
+    /// [`Solver::validate_answer`](crate::Solver::validate_answer).
+    fn validate_answer(&self, part: u8, answer: &str) -> Result<(), AnswerValidationError> {
+        let _ = part;
+        check_answer_shape(answer)
+    }
+
+    /// Get arena allocation statistics recorded while parsing
+    fn parse_arena_stats(&self) -> ArenaStats {
+        ArenaStats::default()
+    }
 
     /// Get the year for this solver
     fn year(&self) -> u16;
@@ -115,31 +223,48 @@ pub trait DynSolver {
     /// Get the number of parts this solver supports
     fn parts(&self) -> u8;
 
-    /// Convenience: get parse duration as TimeDelta
+    /// Get the parse start time (wall clock, UTC)
+    fn parse_start(&self) -> DateTime<Utc> {
+        self.parse_timing().started_at
+    }
+
+    /// Get the parse end time (wall clock, UTC)
+    fn parse_end(&self) -> DateTime<Utc> {
+        self.parse_timing().finished_at
+    }
+
+    /// Convenience: get monotonic parse duration as TimeDelta
     fn parse_duration(&self) -> TimeDelta {
-        self.parse_end() - self.parse_start()
+        self.parse_timing().elapsed
+    }
+
+    /// Convenience: get parse CPU time, if measurable on this platform
+    fn parse_cpu_duration(&self) -> Option<TimeDelta> {
+        self.parse_timing().cpu_time
     }
 }
 
 impl<'a, S: SolverExt> DynSolver for SolverInstance<'a, S> {
     fn solve(&mut self, part: u8) -> Result<SolveResult, SolveError> {
-        let solve_start = Utc::now();
-        let answer = S::solve_part_checked_range(&mut self.shared, part)?;
-        let solve_end = Utc::now();
+        let shared = &mut self.shared;
+        let (answer, timing) = Timing::measure(|| S::solve_part_checked_range(shared, part));
 
         Ok(SolveResult {
-            answer,
-            solve_start,
-            solve_end,
+            answer: answer?,
+            timing,
         })
     }
 
-    fn parse_start(&self) -> DateTime<Utc> {
-        self.parse_start
+    fn parse_timing(&self) -> Timing {
+        self.parse_timing
     }
 
-    fn parse_end(&self) -> DateTime<Utc> {
-        self.parse_end
+    fn validate_answer(&self, part: u8, answer: &str) -> Result<(), AnswerValidationError> {
+        S::validate_answer(part, answer)
+    }
+
+    fn parse_arena_stats(&self) -> ArenaStats {
+        self.parse_arena_stats
     }
 
     fn year(&self) -> u16 {
@@ -154,3 +279,42 @@ impl<'a, S: SolverExt> DynSolver for SolverInstance<'a, S> {
         S::PARTS
     }
 }
+
+impl<S: SolverExt> DynSolver for OwnedSolverInstance<S> {
+    fn solve(&mut self, part: u8) -> Result<SolveResult, SolveError> {
+        self.instance.solve(part)
+    }
+
+    fn parse_timing(&self) -> Timing {
+        self.instance.parse_timing()
+    }
+
+    fn validate_answer(&self, part: u8, answer: &str) -> Result<(), AnswerValidationError> {
+        self.instance.validate_answer(part, answer)
+    }
+
+    fn parse_arena_stats(&self) -> ArenaStats {
+        self.instance.parse_arena_stats()
+    }
+
+    fn year(&self) -> u16 {
+        self.instance.year()
+    }
+
+    fn day(&self) -> u8 {
+        self.instance.day()
+    }
+
+    fn parts(&self) -> u8 {
+        self.instance.parts()
+    }
+}
+
+/// A [`DynSolver`] that can be moved to another thread
+///
+/// Implemented for every `DynSolver + Send`, e.g. an [`OwnedSolverInstance`] whose
+/// solver's `SharedData` is `Send`. Returned by
+/// [`SolverRegistry::create_owned_solver`](crate::SolverRegistry::create_owned_solver).
+pub trait SendDynSolver: DynSolver + Send {}
+
+impl<T: DynSolver + Send + ?Sized> SendDynSolver for T {}
@@ -16,7 +16,7 @@
 //! # Quick Example
 //!
 //! ```
-//! use aoc_solver::{AocParser, ParseError, SolverRegistryBuilder, SolveError, Solver, SolverInstance};
+//! use aoc_solver::{AocParser, Arena, ParseError, SolverRegistryBuilder, SolveError, Solver, SolverInstance};
 //!
 //! // Define a solver
 //! pub struct MyDay1;
@@ -24,7 +24,7 @@
 //! impl AocParser for MyDay1 {
 //!     type SharedData<'a> = Vec<i32>;
 //!     
-//!     fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+//!     fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 //!         input.lines()
 //!             .map(|line| line.parse().map_err(|_|
 //!                 ParseError::InvalidFormat("Expected integer".to_string())))
@@ -81,6 +81,27 @@
 //! struct Day1Solver;
 //! ```
 //!
+//! ## Arena Allocation
+//!
+//! Each [`SolverInstance`] owns a bump [`Arena`] and passes it to [`AocParser::parse`].
+//! Parsers can allocate nodes in it and link them with `&'a` references; the whole
+//! arena is freed at once when the instance drops. Allocation counts and bytes are
+//! reported through [`DynSolver::parse_arena_stats`].
+//!
+//! ## Owned Instances
+//!
+//! [`SolverRegistry::create_owned_solver`] returns an [`OwnedSolverInstance`] that keeps
+//! its input in an `Arc<str>`, so it is `'static` and, for solvers whose `SharedData` is
+//! `Send`, can be moved across threads as a [`SendDynSolver`]. Register such solvers with
+//! `#[aoc(..., send)]` or [`SendSolver`].
+//!
+//! ## Answer Validation
+//!
+//! Before an answer is submitted it is checked by [`DynSolver::validate_answer`], which
+//! defaults to [`check_answer_shape`] (non-empty, no whitespace, not zero or negative).
+//! Override [`Solver::validate_answer`] or [`PartSolver::validate`] to tighten or relax
+//! the checks for a particular puzzle.
+//!
 //! ## Part Dependencies
 //!
 //! Parts can share data through mutations to the `SharedData` structure:
@@ -89,19 +110,26 @@
 //!
 //! See the examples directory for complete demonstrations.
 
+mod arena;
 mod error;
 mod instance;
 mod registry;
 mod solver;
+mod timing;
+mod validation;
 
 // Re-export public API
-pub use error::{ParseError, RegistrationError, SolveError, SolverError};
-pub use instance::{DynSolver, SolveResult, SolverInstance};
+pub use arena::{Arena, ArenaStats};
+pub use error::{AnswerValidationError, ParseError, RegistrationError, SolveError, SolverError};
+pub use instance::{DynSolver, OwnedSolverInstance, SendDynSolver, SolveResult, SolverInstance};
 pub use registry::{
-    BASE_YEAR, CAPACITY, DAYS_PER_YEAR, MAX_YEARS, RegisterableSolver, SolverFactory, SolverInfo,
-    SolverPlugin, SolverRegistry, SolverRegistryBuilder, SolverRegistryStorage,
+    BASE_YEAR, BytesSolverFactory, CAPACITY, DAYS_PER_YEAR, MAX_YEARS, OwnedSolverFactory,
+    RegisterableSolver, SendSolver, SolverFactory, SolverInfo, SolverPlugin, SolverRegistry,
+    SolverRegistryBuilder, SolverRegistryStorage,
 };
-pub use solver::{AocParser, PartSolver, Solver, SolverExt};
+pub use solver::{AocBytesParser, AocParser, PartSolver, Solver, SolverExt};
+pub use timing::Timing;
+pub use validation::{MAX_ANSWER_LEN, check_answer_shape};
 
 // Re-export chrono types for timing
 pub use chrono::{DateTime, TimeDelta, Utc};
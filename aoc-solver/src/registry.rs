@@ -6,9 +6,12 @@
 //! - [`SolverRegistryBuilder`] - Builder pattern for constructing registries
 //! - [`SolverRegistryStorage`] - Internal storage with efficient year/day indexing
 //! - [`SolverFactory`] - Thread-safe factory function type (always Send + Sync)
+//! - [`OwnedSolverFactory`] - Factory for owned, `Send` solver instances
+//! - [`BytesSolverFactory`] - Factory for solver instances over raw input bytes
 //! - [`SolverInfo`] - Metadata about registered solvers
 //! - [`RegisterableSolver`] - Trait for self-registering solvers
 //! - [`SolverPlugin`] - Plugin system for automatic solver discovery
+//! - [`SendSolver`] - Plugin adapter that also registers owned `Send` instances
 //!
 //! # Storage Layout
 //!
@@ -34,7 +37,9 @@
 //! ```
 
 use crate::error::{ParseError, RegistrationError, SolverError};
-use crate::instance::{DynSolver, SolverInstance};
+use crate::instance::{DynSolver, OwnedSolverInstance, SendDynSolver, SolverInstance};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 // ============================================================================
 // Storage Constants and Index Calculation
@@ -79,6 +84,14 @@ fn from_index(index: usize) -> (u16, u8) {
 pub type SolverFactory =
     Box<dyn for<'a> Fn(&'a str) -> Result<Box<dyn DynSolver + 'a>, ParseError> + Send + Sync>;
 
+/// Thread-safe factory function type for creating owned, `Send` solver instances
+pub type OwnedSolverFactory =
+    Box<dyn Fn(Arc<str>) -> Result<Box<dyn SendDynSolver>, ParseError> + Send + Sync>;
+
+/// Thread-safe factory function type for creating solver instances from raw bytes
+pub type BytesSolverFactory =
+    Box<dyn for<'a> Fn(&'a [u8]) -> Result<Box<dyn DynSolver + 'a>, ParseError> + Send + Sync>;
+
 /// Metadata about a registered solver
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SolverInfo {
@@ -93,9 +106,42 @@ pub struct SolverInfo {
 /// Factory entry with metadata
 struct SolverFactoryEntry {
     factory: SolverFactory,
+    bytes_factory: Option<BytesSolverFactory>,
+    owned_factory: Option<OwnedSolverFactory>,
     parts: u8,
 }
 
+impl SolverFactoryEntry {
+    /// Create an entry with only a borrowed-input factory
+    fn new<F>(parts: u8, factory: F) -> Self
+    where
+        F: for<'a> Fn(&'a str) -> Result<Box<dyn DynSolver + 'a>, ParseError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            factory: Box::new(factory),
+            bytes_factory: None,
+            owned_factory: None,
+            parts,
+        }
+    }
+
+    /// Create an entry with borrowed and bytes factories for a solver type
+    fn for_solver<S: crate::solver::Solver + 'static>(year: u16, day: u8) -> Self {
+        Self {
+            bytes_factory: Some(Box::new(move |input: &[u8]| {
+                Ok(Box::new(SolverInstance::<S>::from_bytes(year, day, input)?))
+            })),
+            ..Self::new(S::PARTS, move |input: &str| {
+                // SolverInstance::new handles parsing and timing
+                Ok(Box::new(SolverInstance::<S>::new(year, day, input)?))
+            })
+        }
+    }
+}
+
 /// Builder for constructing a SolverRegistry with fluent API
 ///
 /// The builder pattern allows for method chaining and ensures the registry
@@ -149,7 +195,47 @@ impl SolverRegistryBuilder {
             + Sync
             + 'static,
     {
-        self.storage.register(year, day, parts, factory)?;
+        self.storage
+            .register(year, day, SolverFactoryEntry::new(parts, factory))?;
+        Ok(self)
+    }
+
+    /// Register a solver factory together with an owned-instance factory
+    ///
+    /// Solvers registered this way can also be created with
+    /// [`SolverRegistry::create_owned_solver`]. Use this for solvers whose
+    /// `SharedData` is `Send`.
+    ///
+    /// # Arguments
+    /// * `year` - The Advent of Code year (2015-2034)
+    /// * `day` - The day number (1-25)
+    /// * `parts` - Number of parts this solver supports
+    /// * `factory` - A function that takes borrowed input and returns a boxed DynSolver
+    /// * `owned_factory` - A function that takes shared input and returns a boxed SendDynSolver
+    ///
+    /// # Returns
+    /// * `Ok(&mut Self)` - Builder with the solver registered, ready for chaining
+    /// * `Err(RegistrationError)` - Invalid year/day or duplicate registration
+    pub fn register_owned<F, O>(
+        &mut self,
+        year: u16,
+        day: u8,
+        parts: u8,
+        factory: F,
+        owned_factory: O,
+    ) -> Result<&mut Self, RegistrationError>
+    where
+        F: for<'a> Fn(&'a str) -> Result<Box<dyn DynSolver + 'a>, ParseError>
+            + Send
+            + Sync
+            + 'static,
+        O: Fn(Arc<str>) -> Result<Box<dyn SendDynSolver>, ParseError> + Send + Sync + 'static,
+    {
+        let entry = SolverFactoryEntry {
+            owned_factory: Some(Box::new(owned_factory)),
+            ..SolverFactoryEntry::new(parts, factory)
+        };
+        self.storage.register(year, day, entry)?;
         Ok(self)
     }
 
@@ -284,6 +370,66 @@ impl SolverRegistry {
         let entry = self.storage.get_entry(year, day)?;
         (entry.factory)(input).map_err(SolverError::ParseError)
     }
+
+    /// Create a solver instance from raw input bytes
+    ///
+    /// Solvers registered through the plugin system or [`RegisterableSolver`] parse
+    /// with [`AocParser::parse_bytes`](crate::AocParser::parse_bytes), so byte-based
+    /// solvers skip UTF-8 validation. Other factories validate UTF-8 first.
+    ///
+    /// # Arguments
+    /// * `year` - The Advent of Code year
+    /// * `day` - The day number (1-25)
+    /// * `input` - The raw input bytes (e.g. a memory-mapped cached input)
+    ///
+    /// # Returns
+    /// * `Ok(Box<dyn DynSolver>)` - Successfully created solver
+    /// * `Err(SolverError)` - Solver not found, invalid year/day, or parsing failed
+    pub fn create_solver_from_bytes<'a>(
+        &self,
+        year: u16,
+        day: u8,
+        input: &'a [u8],
+    ) -> Result<Box<dyn DynSolver + 'a>, SolverError> {
+        let entry = self.storage.get_entry(year, day)?;
+        match &entry.bytes_factory {
+            Some(bytes_factory) => bytes_factory(input).map_err(SolverError::ParseError),
+            None => {
+                let input = std::str::from_utf8(input).map_err(|e| {
+                    ParseError::InvalidFormat(format!("Input is not valid UTF-8: {}", e))
+                })?;
+                (entry.factory)(input).map_err(SolverError::ParseError)
+            }
+        }
+    }
+
+    /// Create an owned, `Send` solver instance for a specific year and day
+    ///
+    /// The returned solver keeps the input alive itself, so it is not tied to the
+    /// caller's borrow and can be moved to other threads.
+    ///
+    /// # Arguments
+    /// * `year` - The Advent of Code year
+    /// * `day` - The day number (1-25)
+    /// * `input` - The input for the problem (shared, not copied, if already an `Arc<str>`)
+    ///
+    /// # Returns
+    /// * `Ok(Box<dyn SendDynSolver>)` - Successfully created solver
+    /// * `Err(SolverError)` - Solver not found, invalid year/day, owned instances not
+    ///   supported by this solver, or parsing failed
+    pub fn create_owned_solver(
+        &self,
+        year: u16,
+        day: u8,
+        input: impl Into<Arc<str>>,
+    ) -> Result<Box<dyn SendDynSolver>, SolverError> {
+        let entry = self.storage.get_entry(year, day)?;
+        let owned_factory = entry
+            .owned_factory
+            .as_ref()
+            .ok_or(SolverError::OwnedNotSupported(year, day))?;
+        owned_factory(input.into()).map_err(SolverError::ParseError)
+    }
 }
 
 /// Trait for solvers that can register themselves with a registry builder
@@ -302,14 +448,14 @@ impl SolverRegistry {
 /// # Example
 ///
 /// ```no_run
-/// use aoc_solver::{AocParser, ParseError, RegisterableSolver, SolverRegistryBuilder, SolveError, Solver};
+/// use aoc_solver::{AocParser, Arena, ParseError, RegisterableSolver, SolverRegistryBuilder, SolveError, Solver};
 ///
 /// struct MyDay1;
 ///
 /// impl AocParser for MyDay1 {
 ///     type SharedData<'a> = ();
 ///     
-///     fn parse(_: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(_: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         Ok(())
 ///     }
 /// }
@@ -363,10 +509,10 @@ where
         year: u16,
         day: u8,
     ) -> Result<&'a mut SolverRegistryBuilder, RegistrationError> {
-        builder.register(year, day, S::PARTS, move |input: &str| {
-            // SolverInstance::new handles parsing and timing
-            Ok(Box::new(SolverInstance::<S>::new(year, day, input)?))
-        })
+        builder
+            .storage
+            .register(year, day, SolverFactoryEntry::for_solver::<S>(year, day))?;
+        Ok(builder)
     }
 
     fn parts(&self) -> u8 {
@@ -382,14 +528,14 @@ where
 /// # Example
 ///
 /// ```no_run
-/// use aoc_solver::{AocParser, ParseError, SolveError, Solver, SolverPlugin};
+/// use aoc_solver::{AocParser, Arena, ParseError, SolveError, Solver, SolverPlugin};
 ///
 /// struct Day1Solver;
 ///
 /// impl AocParser for Day1Solver {
 ///     type SharedData<'a> = ();
 ///     
-///     fn parse(_: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(_: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         Ok(())
 ///     }
 /// }
@@ -425,6 +571,87 @@ pub struct SolverPlugin {
 // Enable plugin collection via inventory
 inventory::collect!(SolverPlugin);
 
+/// Plugin adapter that registers a solver with owned-instance support
+///
+/// Wrapping a solver in `SendSolver` makes its plugin registration use
+/// [`SolverRegistryBuilder::register_owned`], so the solver can be created with
+/// [`SolverRegistry::create_owned_solver`]. Requires the solver's `SharedData`
+/// to be `Send`. `#[derive(AutoRegisterSolver)]` uses it when `send` is set in
+/// the `#[aoc(...)]` attribute.
+///
+/// # Example
+///
+/// ```no_run
+/// use aoc_solver::{AocParser, Arena, ParseError, SendSolver, SolveError, Solver, SolverPlugin};
+///
+/// struct Day1Solver;
+///
+/// impl AocParser for Day1Solver {
+///     type SharedData<'a> = Vec<u32>;
+///
+///     fn parse<'a>(_: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+///         Ok(Vec::new())
+///     }
+/// }
+///
+/// impl Solver for Day1Solver {
+///     const PARTS: u8 = 1;
+///
+///     fn solve_part(_: &mut Self::SharedData<'_>, _: u8) -> Result<String, SolveError> {
+///         Err(SolveError::PartNotImplemented(0))
+///     }
+/// }
+///
+/// inventory::submit! {
+///     SolverPlugin {
+///         year: 2023,
+///         day: 1,
+///         solver: &SendSolver::<Day1Solver>::new(),
+///         tags: &[],
+///     }
+/// }
+/// ```
+pub struct SendSolver<S>(PhantomData<fn() -> S>);
+
+impl<S> SendSolver<S> {
+    /// Create the adapter (usable in `inventory::submit!`)
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for SendSolver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> RegisterableSolver for SendSolver<S>
+where
+    S: crate::solver::Solver + 'static,
+    for<'a> S::SharedData<'a>: Send,
+{
+    fn register_with<'a>(
+        &self,
+        builder: &'a mut SolverRegistryBuilder,
+        year: u16,
+        day: u8,
+    ) -> Result<&'a mut SolverRegistryBuilder, RegistrationError> {
+        let entry = SolverFactoryEntry {
+            owned_factory: Some(Box::new(move |input: Arc<str>| {
+                Ok(Box::new(OwnedSolverInstance::<S>::new(year, day, input)?))
+            })),
+            ..SolverFactoryEntry::for_solver::<S>(year, day)
+        };
+        builder.storage.register(year, day, entry)?;
+        Ok(builder)
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+}
+
 /// Macro to register a solver with the registry builder
 ///
 /// This macro simplifies the registration process by automatically creating
@@ -433,14 +660,14 @@ inventory::collect!(SolverPlugin);
 /// # Example
 ///
 /// ```
-/// use aoc_solver::{AocParser, register_solver, ParseError, SolverRegistryBuilder, SolveError, Solver, SolverRegistry};
+/// use aoc_solver::{AocParser, Arena, register_solver, ParseError, SolverRegistryBuilder, SolveError, Solver, SolverRegistry};
 ///
 /// struct MyDay1Solver;
 ///
 /// impl AocParser for MyDay1Solver {
 ///     type SharedData<'a> = ();
 ///     
-///     fn parse(_: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(_: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         Ok(())
 ///     }
 /// }
@@ -498,32 +725,22 @@ impl SolverRegistryStorage {
         }
     }
 
-    /// Register a solver factory with explicit parts count
+    /// Register a solver factory entry
     ///
     /// Returns error if year/day is out of bounds or already registered.
-    fn register<F>(
+    fn register(
         &mut self,
         year: u16,
         day: u8,
-        parts: u8,
-        factory: F,
-    ) -> Result<(), RegistrationError>
-    where
-        F: for<'a> Fn(&'a str) -> Result<Box<dyn DynSolver + 'a>, ParseError>
-            + Send
-            + Sync
-            + 'static,
-    {
+        entry: SolverFactoryEntry,
+    ) -> Result<(), RegistrationError> {
         let index = calc_index(year, day).ok_or(RegistrationError::InvalidYearDay(year, day))?;
 
         if self.entries[index].is_some() {
             return Err(RegistrationError::DuplicateSolverFactory(year, day));
         }
 
-        self.entries[index] = Some(SolverFactoryEntry {
-            factory: Box::new(factory),
-            parts,
-        });
+        self.entries[index] = Some(entry);
         Ok(())
     }
 
@@ -1,23 +1,28 @@
 //! Core solver trait and related types
 
-use crate::error::{ParseError, SolveError};
+use crate::arena::Arena;
+use crate::error::{AnswerValidationError, ParseError, SolveError};
+use crate::validation::check_answer_shape;
 
 /// Trait for parsing AOC puzzle input into shared data
 ///
 /// This trait defines the shared data type and parsing logic for a solver,
 /// providing clean separation between parsing and solving concerns.
 ///
+/// Parsing receives the solver instance's [`Arena`], which lives as long as the
+/// input. Parsers that build owned data simply ignore it.
+///
 /// # Example
 ///
 /// ```
-/// use aoc_solver::{AocParser, ParseError};
+/// use aoc_solver::{AocParser, Arena, ParseError};
 ///
 /// struct Day1;
 ///
 /// impl AocParser for Day1 {
 ///     type SharedData<'a> = Vec<i32>;
 ///     
-///     fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         input
 ///             .lines()
 ///             .map(|l| l.parse().map_err(|_| ParseError::InvalidFormat("bad int".into())))
@@ -31,10 +36,119 @@ pub trait AocParser {
     /// Use any ownership strategy:
     /// - `Vec<T>` or custom structs for owned data (simplest, supports mutation)
     /// - `&'a str` for zero-copy borrowed data when no transformation is needed
+    /// - `&'a Node` links into the instance's [`Arena`]
     type SharedData<'a>;
 
     /// Parse the input string into the shared data structure.
-    fn parse<'a>(input: &'a str) -> Result<Self::SharedData<'a>, ParseError>;
+    ///
+    /// The arena lives as long as the solver instance, so the shared data may hold
+    /// `&'a` references into it; everything allocated there is freed at once when
+    /// the instance drops.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_solver::{AocParser, Arena, ParseError};
+    ///
+    /// struct Node<'a> {
+    ///     value: u32,
+    ///     next: Option<&'a Node<'a>>,
+    /// }
+    ///
+    /// struct Day1;
+    ///
+    /// impl AocParser for Day1 {
+    ///     type SharedData<'a> = Option<&'a Node<'a>>;
+    ///
+    ///     fn parse<'a>(
+    ///         input: &'a str,
+    ///         arena: &'a Arena,
+    ///     ) -> Result<Self::SharedData<'a>, ParseError> {
+    ///         let mut head = None;
+    ///         for line in input.lines() {
+    ///             let value = line.parse().map_err(|_| ParseError::InvalidFormat(line.into()))?;
+    ///             head = Some(&*arena.alloc(Node { value, next: head }));
+    ///         }
+    ///         Ok(head)
+    ///     }
+    /// }
+    ///
+    /// let arena = Arena::new();
+    /// let head = Day1::parse("1\n2", &arena).unwrap().unwrap();
+    /// assert_eq!((head.value, head.next.unwrap().value), (2, 1));
+    /// ```
+    fn parse<'a>(input: &'a str, arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError>;
+
+    /// Parse raw input bytes into the shared data structure.
+    ///
+    /// The default implementation validates UTF-8 and delegates to [`parse`](Self::parse).
+    /// Types implementing [`AocBytesParser`] skip the validation entirely.
+    fn parse_bytes<'a>(
+        input: &'a [u8],
+        arena: &'a Arena,
+    ) -> Result<Self::SharedData<'a>, ParseError> {
+        let input = std::str::from_utf8(input)
+            .map_err(|e| ParseError::InvalidFormat(format!("Input is not valid UTF-8: {}", e)))?;
+        Self::parse(input, arena)
+    }
+}
+
+/// Trait for parsing AOC puzzle input directly from bytes
+///
+/// An alternative to [`AocParser`] for performance-focused solutions: the input is
+/// handed over as `&[u8]` without UTF-8 validation, and `SharedData<'a>` may borrow
+/// from it (including from a memory-mapped input). Every `AocBytesParser` is also an
+/// [`AocParser`] through a blanket implementation, so it works with `PartSolver<N>`,
+/// `#[derive(AocSolver)]` and the registry unchanged.
+///
+/// # Example
+///
+/// ```
+/// use aoc_solver::{AocBytesParser, AocParser, Arena, ParseError};
+///
+/// struct Day1;
+///
+/// impl AocBytesParser for Day1 {
+///     type SharedData<'a> = Vec<&'a [u8]>;
+///
+///     fn parse_bytes<'a>(
+///         input: &'a [u8],
+///         _arena: &'a Arena,
+///     ) -> Result<Self::SharedData<'a>, ParseError> {
+///         Ok(input.split(|&b| b == b'\n').collect())
+///     }
+/// }
+///
+/// let arena = Arena::new();
+/// let lines = <Day1 as AocParser>::parse("ab\ncd", &arena).unwrap();
+/// assert_eq!(lines, vec![b"ab".as_slice(), b"cd".as_slice()]);
+/// ```
+pub trait AocBytesParser {
+    /// The shared data structure that holds parsed input and intermediate results.
+    type SharedData<'a>;
+
+    /// Parse the raw input bytes into the shared data structure.
+    ///
+    /// As with [`AocParser::parse`], the shared data may borrow from the arena.
+    fn parse_bytes<'a>(
+        input: &'a [u8],
+        arena: &'a Arena,
+    ) -> Result<Self::SharedData<'a>, ParseError>;
+}
+
+impl<T: AocBytesParser> AocParser for T {
+    type SharedData<'a> = <T as AocBytesParser>::SharedData<'a>;
+
+    fn parse<'a>(input: &'a str, arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        <T as AocBytesParser>::parse_bytes(input.as_bytes(), arena)
+    }
+
+    fn parse_bytes<'a>(
+        input: &'a [u8],
+        arena: &'a Arena,
+    ) -> Result<Self::SharedData<'a>, ParseError> {
+        <T as AocBytesParser>::parse_bytes(input, arena)
+    }
 }
 
 /// Trait for solving a specific part of an AOC puzzle.
@@ -45,14 +159,14 @@ pub trait AocParser {
 /// # Example
 ///
 /// ```
-/// use aoc_solver::{AocParser, PartSolver, ParseError, SolveError};
+/// use aoc_solver::{AocParser, Arena, PartSolver, ParseError, SolveError};
 ///
 /// struct Day1;
 ///
 /// impl AocParser for Day1 {
 ///     type SharedData<'a> = Vec<i32>;
 ///     
-///     fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         input
 ///             .lines()
 ///             .map(|l| l.parse().map_err(|_| ParseError::InvalidFormat("bad int".into())))
@@ -76,6 +190,14 @@ pub trait PartSolver<const N: u8>: AocParser {
     /// * `Ok(String)` - The answer for this part
     /// * `Err(SolveError)` - An error occurred while solving
     fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError>;
+
+    /// Check an answer produced by [`solve`](Self::solve) before it is submitted.
+    ///
+    /// The default runs [`check_answer_shape`]. `#[derive(AocSolver)]` dispatches
+    /// [`Solver::validate_answer`] to this method.
+    fn validate(answer: &str) -> Result<(), AnswerValidationError> {
+        check_answer_shape(answer)
+    }
 }
 
 /// Core trait that all Advent of Code solvers must implement.
@@ -87,7 +209,7 @@ pub trait PartSolver<const N: u8>: AocParser {
 /// # Example
 ///
 /// ```
-/// use aoc_solver::{AocParser, ParseError, SolveError, Solver};
+/// use aoc_solver::{AocParser, Arena, ParseError, SolveError, Solver};
 ///
 /// struct Day1Solver;
 ///
@@ -99,7 +221,7 @@ pub trait PartSolver<const N: u8>: AocParser {
 /// impl AocParser for Day1Solver {
 ///     type SharedData<'a> = SharedData;
 ///
-///     fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+///     fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
 ///         let numbers = input.lines()
 ///             .map(|line| line.parse().map_err(|_|
 ///                 ParseError::InvalidFormat("Expected integer".to_string())))
@@ -146,6 +268,20 @@ pub trait Solver: AocParser {
     /// * `Err(SolveError::PartNotImplemented)` - The part is not implemented
     /// * `Err(SolveError::SolveFailed)` - An error occurred while solving
     fn solve_part(shared: &mut Self::SharedData<'_>, part: u8) -> Result<String, SolveError>;
+
+    /// Check an answer for a part before it is submitted
+    ///
+    /// Answers that fail validation are reported as warnings and never submitted.
+    /// The default runs [`check_answer_shape`]; override it for puzzles whose answers
+    /// legitimately fall outside those rules (e.g. an answer of `0`).
+    ///
+    /// # Arguments
+    /// * `part` - The part number the answer belongs to
+    /// * `answer` - The answer returned by [`solve_part`](Self::solve_part)
+    fn validate_answer(part: u8, answer: &str) -> Result<(), AnswerValidationError> {
+        let _ = part;
+        check_answer_shape(answer)
+    }
 }
 
 pub trait SolverExt: Solver {
@@ -0,0 +1,106 @@
+//! Timing measurement for parse and solve operations
+//!
+//! Durations are measured with [`std::time::Instant`], which is monotonic and
+//! unaffected by system clock adjustments. Wall-clock timestamps are recorded
+//! separately for logging (e.g. submission logs), and per-thread CPU time is
+//! captured when the platform supports it.
+
+use chrono::{DateTime, TimeDelta, Utc};
+use cpu_time::ThreadTime;
+use std::time::{Duration, Instant};
+
+/// Timing information for a single measured operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    /// Wall-clock time when the operation started (UTC)
+    pub started_at: DateTime<Utc>,
+    /// Wall-clock time when the operation finished (UTC)
+    pub finished_at: DateTime<Utc>,
+    /// Monotonic elapsed time
+    pub elapsed: TimeDelta,
+    /// CPU time consumed by the measuring thread (None if unsupported)
+    pub cpu_time: Option<TimeDelta>,
+}
+
+impl Timing {
+    /// Run `f` and measure how long it takes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use aoc_solver::Timing;
+    ///
+    /// let (sum, timing) = Timing::measure(|| (1..=100).sum::<u32>());
+    /// assert_eq!(sum, 5050);
+    /// assert!(timing.elapsed >= chrono::TimeDelta::zero());
+    /// ```
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Timing) {
+        let stopwatch = Stopwatch::start();
+        let value = f();
+        (value, stopwatch.stop())
+    }
+}
+
+/// Running measurement started by [`Stopwatch::start`]
+struct Stopwatch {
+    started_at: DateTime<Utc>,
+    instant: Instant,
+    cpu: Option<ThreadTime>,
+}
+
+impl Stopwatch {
+    /// Capture the wall clock, monotonic clock and thread CPU clock
+    fn start() -> Self {
+        let started_at = Utc::now();
+        let cpu = ThreadTime::try_now().ok();
+        Self {
+            started_at,
+            cpu,
+            // Take the monotonic reading last so it excludes the other clock reads
+            instant: Instant::now(),
+        }
+    }
+
+    /// Stop the measurement and produce the timing
+    fn stop(self) -> Timing {
+        let elapsed = self.instant.elapsed();
+        let cpu_time = self.cpu.and_then(|start| {
+            ThreadTime::try_now()
+                .ok()
+                .map(|end| end.duration_since(start))
+        });
+        Timing {
+            started_at: self.started_at,
+            finished_at: Utc::now(),
+            elapsed: to_time_delta(elapsed),
+            cpu_time: cpu_time.map(to_time_delta),
+        }
+    }
+}
+
+/// Convert a std duration to TimeDelta, saturating on overflow
+fn to_time_delta(d: Duration) -> TimeDelta {
+    TimeDelta::from_std(d).unwrap_or(TimeDelta::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_is_monotonic_and_ordered() {
+        let ((), timing) = Timing::measure(|| std::thread::sleep(Duration::from_millis(5)));
+
+        assert!(timing.elapsed >= TimeDelta::milliseconds(5));
+        assert!(timing.finished_at >= timing.started_at);
+    }
+
+    #[test]
+    fn test_sleep_uses_little_cpu_time() {
+        let ((), timing) = Timing::measure(|| std::thread::sleep(Duration::from_millis(20)));
+
+        if let Some(cpu) = timing.cpu_time {
+            assert!(cpu < timing.elapsed);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Answer sanity checks run before submission
+//!
+//! Advent of Code answers are short tokens: positive integers, or identifiers like
+//! `ABCDEFGH` and comma-separated lists like `ab,cd,ef`. [`check_answer_shape`]
+//! rejects answers that are clearly not one of these (empty, leftover debug text,
+//! negative numbers, zero), which almost always indicates a bug rather than a
+//! genuine answer. Solvers can replace or extend the checks through
+//! [`Solver::validate_answer`](crate::Solver::validate_answer) or
+//! [`PartSolver::validate`](crate::PartSolver::validate).
+
+use crate::error::AnswerValidationError;
+
+/// Longest answer accepted by [`check_answer_shape`]
+pub const MAX_ANSWER_LEN: usize = 64;
+
+/// Run the built-in answer checks
+///
+/// An answer passes when it is non-empty, at most [`MAX_ANSWER_LEN`] bytes, contains
+/// no whitespace, consists only of ASCII letters, digits, `,`, `-` and `_`, and is not
+/// zero or a negative number.
+///
+/// # Example
+///
+/// ```
+/// use aoc_solver::{AnswerValidationError, check_answer_shape};
+///
+/// assert!(check_answer_shape("142").is_ok());
+/// assert!(check_answer_shape("ab,cd,ef").is_ok());
+/// assert!(matches!(check_answer_shape("-3"), Err(AnswerValidationError::Negative(_))));
+/// assert!(matches!(check_answer_shape("sum = 3"), Err(AnswerValidationError::ContainsWhitespace)));
+/// ```
+pub fn check_answer_shape(answer: &str) -> Result<(), AnswerValidationError> {
+    if answer.is_empty() {
+        return Err(AnswerValidationError::Empty);
+    }
+    if answer.chars().any(char::is_whitespace) {
+        return Err(AnswerValidationError::ContainsWhitespace);
+    }
+    if let Some(c) = answer
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, ',' | '-' | '_')))
+    {
+        return Err(AnswerValidationError::InvalidCharacter(c));
+    }
+    if answer.len() > MAX_ANSWER_LEN {
+        return Err(AnswerValidationError::TooLong(answer.len()));
+    }
+    if let Some(digits) = answer.strip_prefix('-')
+        && !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(AnswerValidationError::Negative(answer.to_string()));
+    }
+    if answer.bytes().all(|b| b == b'0') {
+        return Err(AnswerValidationError::Zero);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_rejects_suspicious_answers() {
+        assert!(matches!(
+            check_answer_shape(""),
+            Err(AnswerValidationError::Empty)
+        ));
+        assert!(matches!(
+            check_answer_shape("0"),
+            Err(AnswerValidationError::Zero)
+        ));
+        assert!(matches!(
+            check_answer_shape("-12"),
+            Err(AnswerValidationError::Negative(_))
+        ));
+        assert!(matches!(
+            check_answer_shape("Some(12)"),
+            Err(AnswerValidationError::InvalidCharacter('('))
+        ));
+        assert!(matches!(
+            check_answer_shape("12\n"),
+            Err(AnswerValidationError::ContainsWhitespace)
+        ));
+        assert!(matches!(
+            check_answer_shape(&"9".repeat(MAX_ANSWER_LEN + 1)),
+            Err(AnswerValidationError::TooLong(_))
+        ));
+    }
+
+    #[test]
+    fn test_accepts_typical_answers() {
+        for answer in ["1", "31415926535", "ABCDEFGH", "aa,bb,cc", "z00,z01", "a-b"] {
+            assert!(check_answer_shape(answer).is_ok(), "{answer}");
+        }
+    }
+
+    // **Feature: answer-validation, Property 1: Positive integers pass**
+    //
+    // *For any* positive integer, its decimal representation passes the shape checks.
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(100))]
+
+        #[test]
+        fn prop_positive_integers_pass(n in 1u64..) {
+            prop_assert!(check_answer_shape(&n.to_string()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Tests for the pre-submission answer validation hook
+
+use aoc_solver::{
+    AnswerValidationError, AocParser, AocSolver, Arena, DynSolver, ParseError, PartSolver,
+    SolveError, SolverInstance,
+};
+
+/// Part 1 keeps the default checks; part 2 accepts a zero answer
+#[derive(AocSolver)]
+#[aoc_solver(max_parts = 2)]
+struct Counter;
+
+impl AocParser for Counter {
+    type SharedData<'a> = Vec<i64>;
+
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        input
+            .lines()
+            .map(|l| {
+                l.parse()
+                    .map_err(|_| ParseError::InvalidFormat(l.to_string()))
+            })
+            .collect()
+    }
+}
+
+impl PartSolver<1> for Counter {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.iter().sum::<i64>().to_string())
+    }
+}
+
+impl PartSolver<2> for Counter {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.iter().filter(|&&n| n > 100).count().to_string())
+    }
+
+    fn validate(answer: &str) -> Result<(), AnswerValidationError> {
+        match answer {
+            "0" => Ok(()),
+            _ => aoc_solver::check_answer_shape(answer),
+        }
+    }
+}
+
+#[test]
+fn test_default_checks_reject_negative_answer() {
+    let mut solver = SolverInstance::<Counter>::new(2023, 1, "3\n-10").unwrap();
+    let answer = solver.solve(1).unwrap().answer;
+
+    assert_eq!(answer, "-7");
+    assert_eq!(
+        solver.validate_answer(1, &answer),
+        Err(AnswerValidationError::Negative("-7".to_string()))
+    );
+}
+
+#[test]
+fn test_part_override_is_dispatched() {
+    let mut solver = SolverInstance::<Counter>::new(2023, 1, "3\n4").unwrap();
+    let answer = solver.solve(2).unwrap().answer;
+
+    assert_eq!(answer, "0");
+    assert_eq!(solver.validate_answer(2, &answer), Ok(()));
+    assert_eq!(
+        solver.validate_answer(1, &answer),
+        Err(AnswerValidationError::Zero)
+    );
+}
+
+#[test]
+fn test_valid_answer_passes() {
+    let mut solver = SolverInstance::<Counter>::new(2023, 1, "30\n12").unwrap();
+    let answer = solver.solve(1).unwrap().answer;
+
+    assert_eq!(solver.validate_answer(1, &answer), Ok(()));
+}
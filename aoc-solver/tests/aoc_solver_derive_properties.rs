@@ -3,7 +3,7 @@
 //! These tests verify the correctness properties defined in the design document
 //! for the trait-based solver redesign.
 
-use aoc_solver::{AocParser, AocSolver, ParseError, PartSolver, SolveError, Solver};
+use aoc_solver::{AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, Solver};
 use proptest::prelude::*;
 
 // Test solver for property tests
@@ -14,7 +14,7 @@ struct TestSolver;
 impl AocParser for TestSolver {
     type SharedData<'a> = Vec<i32>;
 
-    fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         input
             .lines()
             .filter(|l| !l.is_empty())
@@ -59,8 +59,10 @@ mod property_1_part_dispatch {
             part in 1u8..=2
         ) {
             let input = numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
-            let mut shared1 = <TestSolver as AocParser>::parse(&input).unwrap();
-            let mut shared2 = <TestSolver as AocParser>::parse(&input).unwrap();
+            let arena = Arena::new();
+            let mut shared1 = <TestSolver as AocParser>::parse(&input, &arena).unwrap();
+            let arena = Arena::new();
+            let mut shared2 = <TestSolver as AocParser>::parse(&input, &arena).unwrap();
 
             let solver_result = <TestSolver as Solver>::solve_part(&mut shared1, part);
 
@@ -91,7 +93,8 @@ mod property_2_invalid_part_rejection {
         #[test]
         fn invalid_part_returns_not_implemented(invalid_part in prop_oneof![Just(0u8), 3u8..=255]) {
             let input = "1\n2\n3";
-            let mut shared = <TestSolver as AocParser>::parse(input).unwrap();
+            let arena = Arena::new();
+            let mut shared = <TestSolver as AocParser>::parse(input, &arena).unwrap();
 
             let result = <TestSolver as Solver>::solve_part(&mut shared, invalid_part);
 
@@ -120,7 +123,10 @@ mod property_3_read_only {
     impl AocParser for ReadOnlySolver {
         type SharedData<'a> = Vec<i32>;
 
-        fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+        fn parse<'a>(
+            input: &'a str,
+            _arena: &'a Arena,
+        ) -> Result<Self::SharedData<'a>, ParseError> {
             input
                 .lines()
                 .filter(|l| !l.is_empty())
@@ -147,7 +153,8 @@ mod property_3_read_only {
             let input = numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
             let expected_sum: i32 = numbers.iter().sum();
 
-            let mut shared = <ReadOnlySolver as AocParser>::parse(&input).unwrap();
+            let arena = Arena::new();
+            let mut shared = <ReadOnlySolver as AocParser>::parse(&input, &arena).unwrap();
 
             let result = <ReadOnlySolver as Solver>::solve_part(&mut shared, 1).unwrap();
 
@@ -178,7 +185,10 @@ mod property_4_mutation {
     impl AocParser for MutatingSolver {
         type SharedData<'a> = MutableData;
 
-        fn parse(input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+        fn parse<'a>(
+            input: &'a str,
+            _arena: &'a Arena,
+        ) -> Result<Self::SharedData<'a>, ParseError> {
             let numbers: Vec<i32> = input
                 .lines()
                 .filter(|l| !l.is_empty())
@@ -219,7 +229,8 @@ mod property_4_mutation {
             let input = numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
             let expected_sum: i32 = numbers.iter().sum();
 
-            let mut shared = <MutatingSolver as AocParser>::parse(&input).unwrap();
+            let arena = Arena::new();
+            let mut shared = <MutatingSolver as AocParser>::parse(&input, &arena).unwrap();
 
             // Part 1 should compute and cache the sum
             let result1 = <MutatingSolver as Solver>::solve_part(&mut shared, 1).unwrap();
@@ -0,0 +1,105 @@
+//! Tests for arena allocation during parsing
+
+use aoc_solver::{
+    AocParser, AocSolver, Arena, ArenaStats, DynSolver, ParseError, PartSolver, SolveError,
+    SolverInstance,
+};
+
+struct Node<'a> {
+    value: u64,
+    children: &'a [&'a Node<'a>],
+}
+
+impl Node<'_> {
+    fn total(&self) -> u64 {
+        self.value + self.children.iter().map(|c| c.total()).sum::<u64>()
+    }
+}
+
+/// Builds a star: a root with one child per input line
+#[derive(AocSolver)]
+#[aoc_solver(max_parts = 1)]
+struct Tree;
+
+impl AocParser for Tree {
+    type SharedData<'a> = &'a Node<'a>;
+
+    fn parse<'a>(input: &'a str, arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        let children: Vec<&Node> = input
+            .lines()
+            .map(|l| {
+                let value = l
+                    .parse()
+                    .map_err(|_| ParseError::InvalidFormat(l.to_string()))?;
+                Ok(&*arena.alloc(Node {
+                    value,
+                    children: &[],
+                }))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        let children = arena.alloc_slice_copy(&children);
+        Ok(arena.alloc(Node { value: 0, children }))
+    }
+}
+
+impl PartSolver<1> for Tree {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.total().to_string())
+    }
+}
+
+#[test]
+fn test_arena_backed_parse_and_stats() {
+    let mut solver = SolverInstance::<Tree>::new(2021, 1, "1\n2\n3").unwrap();
+
+    assert_eq!(solver.solve(1).unwrap().answer, "6");
+
+    // 3 leaves + children slice + root
+    let stats = solver.parse_arena_stats();
+    assert_eq!(stats.allocations, 5);
+    assert!(stats.bytes >= 3 * size_of::<Node>());
+    assert_eq!(solver.arena_stats(), stats);
+}
+
+#[test]
+fn test_arena_parse_error_is_reported() {
+    let result = SolverInstance::<Tree>::new(2021, 1, "1\nx");
+
+    assert!(matches!(result, Err(ParseError::InvalidFormat(_))));
+}
+
+#[test]
+fn test_arena_parser_called_directly() {
+    let arena = Arena::new();
+    let root = Tree::parse("4\n5", &arena).unwrap();
+
+    assert_eq!(root.total(), 9);
+    assert_eq!(arena.stats().allocations, 4);
+}
+
+#[test]
+fn test_plain_parser_reports_no_arena_use() {
+    #[derive(AocSolver)]
+    #[aoc_solver(max_parts = 1)]
+    struct Plain;
+
+    impl AocParser for Plain {
+        type SharedData<'a> = &'a str;
+
+        fn parse<'a>(
+            input: &'a str,
+            _arena: &'a Arena,
+        ) -> Result<Self::SharedData<'a>, ParseError> {
+            Ok(input)
+        }
+    }
+
+    impl PartSolver<1> for Plain {
+        fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+            Ok(shared.to_string())
+        }
+    }
+
+    let solver = SolverInstance::<Plain>::new(2021, 2, "x").unwrap();
+    assert_eq!(solver.parse_arena_stats(), ArenaStats::default());
+}
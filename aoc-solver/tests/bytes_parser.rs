@@ -0,0 +1,104 @@
+//! Tests for byte-oriented parsing via `AocBytesParser`
+
+use aoc_solver::{
+    AocBytesParser, AocParser, AocSolver, Arena, ParseError, PartSolver, SolveError, Solver,
+    SolverError, SolverRegistryBuilder,
+};
+
+#[derive(AocSolver)]
+#[aoc_solver(max_parts = 1)]
+struct ByteCounter;
+
+impl AocBytesParser for ByteCounter {
+    type SharedData<'a> = &'a [u8];
+
+    fn parse_bytes<'a>(
+        input: &'a [u8],
+        _arena: &'a Arena,
+    ) -> Result<Self::SharedData<'a>, ParseError> {
+        Ok(input)
+    }
+}
+
+impl PartSolver<1> for ByteCounter {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.iter().filter(|&&b| b == 0xff).count().to_string())
+    }
+}
+
+#[derive(AocSolver)]
+#[aoc_solver(max_parts = 1)]
+struct LineCounter;
+
+impl AocParser for LineCounter {
+    type SharedData<'a> = usize;
+
+    fn parse<'a>(input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
+        Ok(input.lines().count())
+    }
+}
+
+impl PartSolver<1> for LineCounter {
+    fn solve(shared: &mut Self::SharedData<'_>) -> Result<String, SolveError> {
+        Ok(shared.to_string())
+    }
+}
+
+fn registry() -> aoc_solver::SolverRegistry {
+    let mut builder = SolverRegistryBuilder::new();
+    aoc_solver::register_solver!(builder, ByteCounter, 2020, 1);
+    aoc_solver::RegisterableSolver::register_with(&LineCounter, &mut builder, 2020, 2).unwrap();
+    builder.build()
+}
+
+#[test]
+fn test_bytes_parser_skips_utf8_validation() {
+    let registry = registry();
+    let input: &[u8] = &[b'a', 0xff, 0xff, b'\n'];
+
+    // register_solver! only provides a str factory, so bytes must be valid UTF-8 there
+    assert!(matches!(
+        registry.create_solver_from_bytes(2020, 1, input),
+        Err(SolverError::ParseError(ParseError::InvalidFormat(_)))
+    ));
+
+    let mut builder = SolverRegistryBuilder::new();
+    aoc_solver::RegisterableSolver::register_with(&ByteCounter, &mut builder, 2020, 1).unwrap();
+    let mut solver = builder
+        .build()
+        .create_solver_from_bytes(2020, 1, input)
+        .unwrap();
+    assert_eq!(solver.solve(1).unwrap().answer, "2");
+}
+
+#[test]
+fn test_str_parser_accepts_valid_utf8_bytes() {
+    let registry = registry();
+
+    let mut solver = registry
+        .create_solver_from_bytes(2020, 2, "a\nb\nc".as_bytes())
+        .unwrap();
+
+    assert_eq!(solver.solve(1).unwrap().answer, "3");
+}
+
+#[test]
+fn test_str_parser_rejects_invalid_utf8_bytes() {
+    let registry = registry();
+
+    let result = registry.create_solver_from_bytes(2020, 2, &[0xff]);
+
+    assert!(matches!(
+        result,
+        Err(SolverError::ParseError(ParseError::InvalidFormat(_)))
+    ));
+}
+
+#[test]
+fn test_bytes_parser_works_with_str_input() {
+    let registry = registry();
+
+    let mut solver = registry.create_solver(2020, 1, "abc").unwrap();
+
+    assert_eq!(solver.solve(1).unwrap().answer, "0");
+}
@@ -2,7 +2,7 @@
 //!
 //! **Feature: solver-part-bounds**
 
-use aoc_solver::{AocParser, ParseError, SolveError, Solver, SolverExt};
+use aoc_solver::{AocParser, Arena, ParseError, SolveError, Solver, SolverExt};
 use proptest::prelude::*;
 
 /// Test solver with configurable PARTS
@@ -11,7 +11,7 @@ struct TestSolver<const N: u8>;
 impl<const N: u8> AocParser for TestSolver<N> {
     type SharedData<'a> = ();
 
-    fn parse(_input: &str) -> Result<Self::SharedData<'_>, ParseError> {
+    fn parse<'a>(_input: &'a str, _arena: &'a Arena) -> Result<Self::SharedData<'a>, ParseError> {
         Ok(())
     }
 }